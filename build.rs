@@ -0,0 +1,102 @@
+//! Generates one test for every sample in `data/answers.toml`, so adding a sample
+//! only needs a new entry there. With `embed-inputs` it also lists the puzzle inputs
+//! to compile in.
+
+use serde::Deserialize;
+use std::{collections::HashSet, fmt::Write, path::Path};
+
+const ANSWERS_PATH: &str = "data/answers.toml";
+
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    #[serde(default)]
+    params: toml::Table,
+    ignore: Option<String>,
+}
+
+impl Entry {
+    /// Like `day11_part2_11_sample_expansion_10`.
+    fn test_name(&self) -> String {
+        let file = Path::new(&self.input)
+            .file_name()
+            .map_or(self.input.clone(), |file| file.to_string_lossy().into());
+        let params = self
+            .params
+            .iter()
+            .map(|(name, value)| format!("_{name}_{value}"))
+            .collect::<String>();
+
+        format!("day{}_part{}_{file}{params}", self.day, self.part)
+            .chars()
+            .map(|c| match c.is_ascii_alphanumeric() {
+                true => c.to_ascii_lowercase(),
+                false => '_',
+            })
+            .collect()
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={ANSWERS_PATH}");
+
+    let manifest = std::fs::read_to_string(ANSWERS_PATH).expect("Couldn't read the answers");
+    let manifest: Manifest = toml::from_str(&manifest).expect("Couldn't parse the answers");
+
+    let mut names = HashSet::new();
+    let mut tests = String::new();
+    for (index, entry) in manifest.answers.iter().enumerate() {
+        if !entry.input.ends_with(".sample") {
+            continue;
+        }
+
+        let mut name = entry.test_name();
+        if !names.insert(name.clone()) {
+            name = format!("{name}_{index}");
+            names.insert(name.clone());
+        }
+
+        writeln!(tests, "#[test]").unwrap();
+        if let Some(reason) = &entry.ignore {
+            writeln!(tests, "#[ignore = {reason:?}]").unwrap();
+        }
+        writeln!(tests, "fn {name}() {{\n    check_sample({index});\n}}\n").unwrap();
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("Cargo sets OUT_DIR");
+    std::fs::write(Path::new(&out_dir).join("sample_tests.rs"), tests)
+        .expect("Couldn't write the sample tests");
+
+    if std::env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        println!("cargo:rerun-if-changed=data");
+        std::fs::write(
+            Path::new(&out_dir).join("embedded_inputs.rs"),
+            embedded_inputs(),
+        )
+        .expect("Couldn't write the embedded inputs");
+    }
+}
+
+/// An array of the input of every day, `None` for the ones that aren't downloaded
+/// yet so they can still be built.
+fn embedded_inputs() -> String {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("Cargo sets CARGO_MANIFEST_DIR");
+
+    let mut inputs = String::from("[\n");
+    for day in 1..=25 {
+        let path = Path::new(&manifest_dir).join(format!("data/{day}.input"));
+        match path.exists() {
+            true => writeln!(inputs, "    Some(include_str!({:?})),", path.display()).unwrap(),
+            false => writeln!(inputs, "    None,").unwrap(),
+        }
+    }
+    inputs + "]\n"
+}
@@ -0,0 +1,274 @@
+//! Playing the intermediate states of a simulation in the terminal.
+
+use crate::{
+    input::Input,
+    render::{Canvas, Format},
+    Solution,
+};
+use anyhow::{Context, Result};
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+/// A solution that can show how its simulation unfolds.
+pub trait Animate: Solution {
+    fn frames(input: &Self::Input) -> Result<Vec<Canvas>>;
+
+    /// Reads and parses `input` and plays or dumps its frames as `args` asks for.
+    fn animate(input: impl Into<Input>, args: &AnimateArgs) -> Result<()> {
+        let input = Self::parse(&input.into().read()?)?;
+        let frames = Self::frames(&input)?;
+
+        if let Some(dir) = &args.dump_frames {
+            let paths = dump(&frames, dir, Self::DAY, args.frame_format)?;
+            println!("Saved {} frames in '{}'.", paths.len(), dir.display());
+        }
+        if args.animate {
+            play(&frames, args.fps, args.paused)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Command line of the day binaries that can animate their simulation.
+#[derive(Debug, clap::Parser)]
+pub struct AnimateArgs {
+    /// Play the simulation in the terminal instead of solving it. While playing, enter
+    /// `p` to pause or resume, nothing to step once paused and `q` to quit
+    #[arg(long)]
+    pub animate: bool,
+    /// Frames per second
+    #[arg(long, default_value_t = 10.0)]
+    pub fps: f64,
+    /// Start paused, stepping through the frames one at a time
+    #[arg(long)]
+    pub paused: bool,
+    /// Save every frame into the given directory instead of solving the puzzle
+    #[arg(long)]
+    pub dump_frames: Option<PathBuf>,
+    /// Format of the saved frames
+    #[arg(long, value_enum, default_value_t = Format::Ppm)]
+    pub frame_format: Format,
+}
+
+impl AnimateArgs {
+    /// Whether the simulation should be shown instead of solving the puzzle.
+    pub fn requested(&self) -> bool {
+        self.animate || self.dump_frames.is_some()
+    }
+}
+
+/// Writes every frame to `dir` as `<day>_<frame>.<extension>`, numbered from 0.
+pub fn dump(frames: &[Canvas], dir: &Path, day: u8, format: Format) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Couldn't create {}", dir.display()))?;
+
+    let width = frames.len().saturating_sub(1).to_string().len().max(4);
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            let path = dir.join(format!("{day}_{i:0width$}.{}", format.extension()));
+            std::fs::write(&path, frame.to_format(format))
+                .with_context(|| format!("Couldn't write {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// An empty line, steps while paused and pauses while playing.
+    Step,
+    TogglePause,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" => Some(Command::Step),
+            "p" => Some(Command::TogglePause),
+            "q" => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Which frame to show and whether to move on by itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Player {
+    frame: usize,
+    frames: usize,
+    paused: bool,
+    done: bool,
+}
+
+impl Player {
+    fn new(frames: usize, paused: bool) -> Self {
+        Self {
+            frame: 0,
+            frames,
+            paused,
+            done: frames == 0,
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.frame + 1 < self.frames {
+            self.frame += 1;
+        } else {
+            self.done = true;
+        }
+    }
+
+    fn handle(&mut self, command: Command) {
+        match command {
+            Command::Step if self.paused => self.advance(),
+            Command::Step | Command::TogglePause => self.paused = !self.paused,
+            Command::Quit => self.done = true,
+        }
+    }
+
+    /// The time between frames passed without a command.
+    fn tick(&mut self) {
+        if !self.paused {
+            self.advance();
+        }
+    }
+}
+
+/// Shows the frames one after another, controlled by lines entered on stdin.
+pub fn play(frames: &[Canvas], fps: f64, paused: bool) -> Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps.max(0.1));
+    let commands = read_commands();
+    let mut player = Player::new(frames.len(), paused);
+    let mut stdout = std::io::stdout();
+
+    // Clear once, later frames only move the cursor back up to draw over the last one.
+    write!(stdout, "\x1b[2J")?;
+    while !player.done {
+        let state = if player.paused {
+            "paused, enter: step, p: play, q: quit"
+        } else {
+            "playing, p: pause, q: quit"
+        };
+        writeln!(
+            stdout,
+            "\x1b[H{}\x1b[2KFrame {}/{}, {state}",
+            frames[player.frame].to_ansi(),
+            player.frame + 1,
+            frames.len(),
+        )?;
+        stdout.flush()?;
+
+        let command = if player.paused {
+            commands.recv().ok().or(Some(Command::Quit))
+        } else {
+            match commands.recv_timeout(delay) {
+                Ok(command) => Some(command),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    std::thread::sleep(delay);
+                    None
+                }
+            }
+        };
+        match command {
+            Some(command) => player.handle(command),
+            None => player.tick(),
+        }
+    }
+
+    Ok(())
+}
+
+/// Commands entered on stdin, read on their own thread so playing doesn't wait for them.
+fn read_commands() -> Receiver<Command> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(command) = Command::parse(&line) {
+                if sender.send(command).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        render::{Rgb, Tile},
+        test_util::temp_dir,
+        Grid,
+    };
+
+    #[test]
+    fn player_test() {
+        let mut player = Player::new(3, false);
+        player.tick();
+        assert_eq!(player.frame, 1);
+
+        player.handle(Command::TogglePause);
+        player.tick();
+        assert_eq!((player.frame, player.paused), (1, true));
+
+        player.handle(Command::Step);
+        assert_eq!(player.frame, 2);
+        player.handle(Command::Step);
+        assert!(player.done);
+        assert_eq!(player.frame, 2);
+    }
+
+    #[test]
+    fn player_quit_test() {
+        let mut player = Player::new(3, true);
+        player.handle(Command::Step);
+        player.handle(Command::Quit);
+        assert_eq!((player.frame, player.done), (1, true));
+
+        assert!(Player::new(0, false).done);
+    }
+
+    #[test]
+    fn command_test() {
+        assert_eq!(Command::parse(""), Some(Command::Step));
+        assert_eq!(Command::parse(" p\n"), Some(Command::TogglePause));
+        assert_eq!(Command::parse("q"), Some(Command::Quit));
+        assert_eq!(Command::parse("x"), None);
+    }
+
+    #[test]
+    fn dump_test() {
+        let dir = temp_dir("animate_dump").join("frames");
+        let frames: Vec<_> = (0..3)
+            .map(|i| {
+                let grid = Grid::filled(2, 1, i);
+                Canvas::new(&grid, |i| {
+                    Tile::new(char::from(b'0' + *i as u8), Rgb::WHITE)
+                })
+            })
+            .collect();
+
+        let paths = dump(&frames, &dir, 14, Format::Ansi).unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                dir.join("14_0000.txt"),
+                dir.join("14_0001.txt"),
+                dir.join("14_0002.txt")
+            ]
+        );
+        let last = std::fs::read_to_string(&paths[2]).unwrap();
+        assert!(last.contains("22"));
+    }
+}
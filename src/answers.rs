@@ -0,0 +1,158 @@
+use crate::{input::Input, Answer, Params};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub const ANSWERS_PATH: &str = "data/answers.toml";
+
+/// The accepted answer for one part of a day using a specific input file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    /// Constants replaced for this input, see [`Solution::solve_with`](crate::Solution::solve_with).
+    #[serde(default)]
+    pub params: Params,
+    /// Why the answer can't be checked yet, skipping it.
+    pub ignore: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(default, rename = "answer")]
+    answers: Vec<ExpectedAnswer>,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Pass,
+    Fail(Answer),
+    Error(anyhow::Error),
+}
+
+impl ExpectedAnswer {
+    /// The input file, followed by the replaced constants if there are any.
+    pub fn input_label(&self) -> String {
+        match self.params.is_empty() {
+            true => self.input.clone(),
+            false => format!("{} ({})", self.input, self.params),
+        }
+    }
+
+    /// Solves the part again and compares it to the accepted answer.
+    pub fn verify(&self) -> Outcome {
+        let Some(solution) = crate::days::solution(self.day) else {
+            return Outcome::Error(anyhow::anyhow!("Day {} is not solved yet", self.day));
+        };
+
+        match solution.solve_with(&Input::path(&self.input), self.part, &self.params) {
+            Ok(answer) if answer.to_string() == self.answer => Outcome::Pass,
+            Ok(answer) => Outcome::Fail(answer),
+            Err(err) => Outcome::Error(err),
+        }
+    }
+}
+
+pub fn parse_answers(manifest: &str) -> Result<Vec<ExpectedAnswer>> {
+    Ok(toml::from_str::<Manifest>(manifest)?.answers)
+}
+
+pub fn load_answers(path: &str) -> Result<Vec<ExpectedAnswer>> {
+    let manifest = std::fs::read_to_string(path)
+        .with_context(|| format!("Couldn't read the answers from {path}"))?;
+
+    parse_answers(&manifest).with_context(|| format!("Couldn't parse the answers in {path}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_answers_test() {
+        let answers = parse_answers(
+            r#"
+            [[answer]]
+            day = 1
+            part = 2
+            input = "data/1_2.sample"
+            answer = "281"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            vec![ExpectedAnswer {
+                day: 1,
+                part: 2,
+                input: "data/1_2.sample".to_string(),
+                answer: "281".to_string(),
+                params: Params::default(),
+                ignore: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_test() {
+        let mut expected = ExpectedAnswer {
+            day: 9,
+            part: 1,
+            input: "data/9.sample".to_string(),
+            answer: "114".to_string(),
+            params: Params::default(),
+            ignore: None,
+        };
+        assert!(matches!(expected.verify(), Outcome::Pass));
+
+        expected.answer = "115".to_string();
+        assert!(matches!(
+            expected.verify(),
+            Outcome::Fail(Answer::Number(114))
+        ));
+
+        expected.input = "data/missing.sample".to_string();
+        assert!(matches!(expected.verify(), Outcome::Error(_)));
+    }
+
+    #[test]
+    fn params_test() {
+        let answers = parse_answers(
+            r#"
+            [[answer]]
+            day = 21
+            part = 1
+            input = "data/21.sample"
+            answer = "16"
+            params = { steps = 6 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers[0].params, Params::new([("steps", 6)]));
+        assert_eq!(answers[0].input_label(), "data/21.sample (steps=6)");
+        assert!(matches!(answers[0].verify(), Outcome::Pass));
+    }
+
+    /// Solves the answer at `index` of the manifest and fails unless it is accepted.
+    fn check_sample(index: usize) {
+        let answers = parse_answers(include_str!("../data/answers.toml")).unwrap();
+        let expected = &answers[index];
+
+        match expected.verify() {
+            Outcome::Pass => {}
+            Outcome::Fail(actual) => panic!(
+                "Day {} part {} of {} is {actual}, expected {}",
+                expected.day,
+                expected.part,
+                expected.input_label(),
+                expected.answer
+            ),
+            Outcome::Error(err) => panic!("{err:#}"),
+        }
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+}
@@ -0,0 +1,241 @@
+use crate::{input::Input, DynSolution};
+use anyhow::{Context, Result};
+use std::time::{Duration, Instant};
+
+pub const PHASES: [&str; 4] = ["load", "parse", "part 1", "part 2"];
+
+/// Summary of the durations of one phase over all iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` if there are no durations.
+    pub fn new(durations: &[Duration]) -> Option<Stats> {
+        let mut durations = durations.to_vec();
+        durations.sort();
+
+        Some(Stats {
+            min: *durations.first()?,
+            median: durations[durations.len() / 2],
+            max: *durations.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    /// Stats for every entry of [`PHASES`], `None` if that phase failed.
+    pub phases: [Option<Stats>; 4],
+}
+
+/// Times loading, parsing and both parts of a solution separately.
+///
+/// A part that fails is left out, so unsolved parts don't stop the other phases
+/// from being measured.
+pub fn bench_day(solution: &dyn DynSolution, input: &Input, iterations: usize) -> Result<DayBench> {
+    let day = solution.day();
+    let mut durations: [Vec<Duration>; 4] = Default::default();
+    let mut solved = [true, true];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let raw = input.read()?;
+        durations[0].push(start.elapsed());
+
+        let start = Instant::now();
+        let input = solution
+            .parse(&raw)
+            .with_context(|| format!("Couldn't parse {input}"))?;
+        durations[1].push(start.elapsed());
+
+        for (part, solved) in solved.iter_mut().enumerate() {
+            if !*solved {
+                continue;
+            }
+
+            let start = Instant::now();
+            let answer = match part {
+                0 => solution.part1(input.as_ref()),
+                _ => solution.part2(input.as_ref()),
+            };
+            match answer {
+                Ok(_) => durations[part + 2].push(start.elapsed()),
+                Err(_) => *solved = false,
+            }
+        }
+    }
+
+    let mut phases = [None; 4];
+    for (stats, durations) in phases.iter_mut().zip(&durations) {
+        *stats = Stats::new(durations);
+    }
+    for (part, solved) in solved.iter().enumerate() {
+        if !solved {
+            phases[part + 2] = None;
+        }
+    }
+
+    Ok(DayBench {
+        day,
+        iterations,
+        phases,
+    })
+}
+
+fn cell(stats: &Option<Stats>, field: fn(&Stats) -> Duration) -> String {
+    stats
+        .as_ref()
+        .map(|stats| format!("{:.2?}", field(stats)))
+        .unwrap_or("-".to_string())
+}
+
+fn rows(benches: &[DayBench]) -> Vec<[String; 5]> {
+    benches
+        .iter()
+        .flat_map(|bench| {
+            PHASES.iter().zip(&bench.phases).map(|(phase, stats)| {
+                [
+                    bench.day.to_string(),
+                    phase.to_string(),
+                    cell(stats, |stats| stats.min),
+                    cell(stats, |stats| stats.median),
+                    cell(stats, |stats| stats.max),
+                ]
+            })
+        })
+        .collect()
+}
+
+const HEADER: [&str; 5] = ["Day", "Phase", "Min", "Median", "Max"];
+
+pub fn to_table(benches: &[DayBench]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6} {:>12} {:>12} {:>12}\n",
+        HEADER[0], HEADER[1], HEADER[2], HEADER[3], HEADER[4]
+    );
+    for [day, phase, min, median, max] in rows(benches) {
+        table += &format!("{day:>3}  {phase:<6} {min:>12} {median:>12} {max:>12}\n");
+    }
+    table
+}
+
+pub fn to_markdown(benches: &[DayBench]) -> String {
+    let mut table = format!("| {} |\n", HEADER.join(" | "));
+    table += "|----:|:------|----:|-------:|----:|\n";
+    for row in rows(benches) {
+        table += &format!("| {} |\n", row.join(" | "));
+    }
+    table
+}
+
+/// Durations are exported in nanoseconds, failed phases as `null`.
+pub fn to_json(benches: &[DayBench]) -> String {
+    let days: Vec<_> = benches
+        .iter()
+        .map(|bench| {
+            let phases: serde_json::Map<_, _> = PHASES
+                .iter()
+                .zip(&bench.phases)
+                .map(|(phase, stats)| {
+                    let stats = stats.map(|stats| {
+                        serde_json::json!({
+                            "min_ns": stats.min.as_nanos() as u64,
+                            "median_ns": stats.median.as_nanos() as u64,
+                            "max_ns": stats.max.as_nanos() as u64,
+                        })
+                    });
+                    (phase.to_string(), stats.into())
+                })
+                .collect();
+
+            serde_json::json!({
+                "day": bench.day,
+                "iterations": bench.iterations,
+                "phases": phases,
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&days).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::{day23::Day23, day9::Day9};
+
+    fn bench() -> DayBench {
+        let stats = Stats {
+            min: Duration::from_micros(1),
+            median: Duration::from_micros(2),
+            max: Duration::from_millis(3),
+        };
+        DayBench {
+            day: 9,
+            iterations: 3,
+            phases: [Some(stats), Some(stats), Some(stats), None],
+        }
+    }
+
+    #[test]
+    fn stats_test() {
+        let durations = [5, 1, 3, 2, 4].map(Duration::from_millis);
+        assert_eq!(
+            Stats::new(&durations),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            })
+        );
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn bench_day_test() {
+        let bench = bench_day(&Day9, &"data/9.sample".into(), 3).unwrap();
+
+        assert_eq!(bench.day, 9);
+        for stats in bench.phases {
+            let stats = stats.unwrap();
+            assert!(stats.min <= stats.median && stats.median <= stats.max);
+        }
+    }
+
+    #[test]
+    fn bench_unsolved_part_test() {
+        let bench = bench_day(&Day23, &"data/23.sample".into(), 1).unwrap();
+        assert!(bench.phases[2].is_some());
+        assert!(bench.phases[3].is_none());
+    }
+
+    #[test]
+    fn to_table_test() {
+        let table = to_table(&[bench()]);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].contains("load") && lines[1].contains("3.00ms"));
+        assert!(lines[4].ends_with("-"));
+    }
+
+    #[test]
+    fn to_markdown_test() {
+        let table = to_markdown(&[bench()]);
+        assert!(table.starts_with("| Day | Phase | Min | Median | Max |\n"));
+        assert!(table.contains("| 9 | part 2 | - | - | - |\n"));
+    }
+
+    #[test]
+    fn to_json_test() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[bench()])).unwrap();
+        assert_eq!(json[0]["day"], 9);
+        assert_eq!(json[0]["phases"]["load"]["max_ns"], 3_000_000);
+        assert!(json[0]["phases"]["part 2"].is_null());
+    }
+}
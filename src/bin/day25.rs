@@ -1,30 +1,12 @@
-use anyhow::Result;
-
-fn part1(_path: &str) -> Result<u32> {
-    todo!()
-}
-
-fn part2(_path: &str) -> Result<u32> {
-    todo!()
-}
+use aoc23::{days::day25::Day25, Solution};
 
 fn main() {
-    todo!();
-    println!("Part1: {}", part1("data/x.input").unwrap());
-    println!("Part1: {}", part2("data/x.input").unwrap());
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn part1_test() {
-        assert_eq!(part1("data/x.sample").unwrap(), 0);
-    }
-
-    #[test]
-    fn part2_test() {
-        assert_eq!(part2("data/x.sample").unwrap(), 0);
-    }
+    println!(
+        "Part1: {}",
+        Day25::solve(aoc23::puzzle_input!(25), 1).unwrap()
+    );
+    println!(
+        "Part2: {}",
+        Day25::solve(aoc23::puzzle_input!(25), 2).unwrap()
+    );
 }
@@ -0,0 +1,191 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the session cookie, also read from `.env`.
+pub const SESSION_VAR: &str = "AOC_COOKIE";
+/// Environment variable overriding the base URL of the Advent of Code site.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Where the inputs of the event these solutions are for are stored.
+pub const DEFAULT_INPUT_PATH: &str = "data/{day}.input";
+/// Where the inputs of every other event are stored, so they don't mix with this one.
+pub const DEFAULT_OTHER_YEAR_INPUT_PATH: &str = "data/{year}/{day}.input";
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Settings read from `$XDG_CONFIG_HOME/aoc23/config.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// Layout of downloaded inputs, `{year}` and `{day}` are replaced.
+    pub input_path: Option<String>,
+}
+
+impl Config {
+    /// Location of the config file, `None` if neither `XDG_CONFIG_HOME` nor `HOME` is set.
+    pub fn path() -> Option<PathBuf> {
+        let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+
+        Some(config_dir.join("aoc23").join("config.toml"))
+    }
+
+    /// Loads the config file, a missing file is the same as an empty one.
+    pub fn load() -> Result<Config> {
+        match Config::path() {
+            Some(path) if path.exists() => Config::from_file(&path),
+            _ => Ok(Config::default()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Config> {
+        let config = std::fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read the config from {}", path.display()))?;
+
+        toml::from_str(&config)
+            .with_context(|| format!("Couldn't parse the config in {}", path.display()))
+    }
+
+    /// Layout of the inputs for the given year, see [`Config::input_path`].
+    pub fn input_path(&self, year: u16) -> String {
+        match &self.input_path {
+            Some(input_path) => input_path.clone(),
+            None if year == DEFAULT_YEAR => DEFAULT_INPUT_PATH.to_string(),
+            None => DEFAULT_OTHER_YEAR_INPUT_PATH.to_string(),
+        }
+    }
+}
+
+/// Picks the session cookie from the environment, the `--session` flag or the config,
+/// in that order.
+pub fn resolve_session(
+    env: Option<String>,
+    flag: Option<String>,
+    config: &Config,
+) -> Result<String> {
+    let session = [env, flag, config.session.clone()]
+        .into_iter()
+        .flatten()
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty());
+
+    match session {
+        Some(session) => Ok(session),
+        None => bail!(
+            "No session cookie found. Set {SESSION_VAR} (or add it to .env), pass --session \
+             or add `session = \"...\"` to {}",
+            Config::path()
+                .map(|path| path.display().to_string())
+                .unwrap_or("the aoc23 config file".to_string())
+        ),
+    }
+}
+
+/// Resolves the session cookie for the current process.
+pub fn session(flag: Option<String>, config: &Config) -> Result<String> {
+    resolve_session(std::env::var(SESSION_VAR).ok(), flag, config)
+}
+
+/// Picks the base URL from the environment or the config, falling back to the real site.
+pub fn resolve_base_url(env: Option<String>, config: &Config) -> String {
+    [env, config.base_url.clone()]
+        .into_iter()
+        .flatten()
+        .map(|base_url| base_url.trim().trim_end_matches('/').to_string())
+        .find(|base_url| !base_url.is_empty())
+        .unwrap_or(DEFAULT_BASE_URL.to_string())
+}
+
+/// Resolves the base URL for the current process.
+pub fn base_url(config: &Config) -> String {
+    resolve_base_url(std::env::var(BASE_URL_VAR).ok(), config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn config(session: Option<&str>) -> Config {
+        Config {
+            session: session.map(str::to_string),
+            ..Config::default()
+        }
+    }
+
+    #[test_case(Some("env"), Some("flag"), Some("config"), "env")]
+    #[test_case(None, Some("flag"), Some("config"), "flag")]
+    #[test_case(None, None, Some("config"), "config")]
+    #[test_case(Some(" "), None, Some("config\n"), "config")]
+    fn resolve_session_test(
+        env: Option<&str>,
+        flag: Option<&str>,
+        from_config: Option<&str>,
+        expected: &str,
+    ) {
+        let session = resolve_session(
+            env.map(str::to_string),
+            flag.map(str::to_string),
+            &config(from_config),
+        )
+        .unwrap();
+        assert_eq!(session, expected);
+    }
+
+    #[test]
+    fn missing_session_test() {
+        let err = resolve_session(None, None, &config(None)).unwrap_err();
+        assert!(err.to_string().starts_with("No session cookie found"));
+    }
+
+    #[test_case(
+        Some("http://localhost:8080/"),
+        Some("http://config"),
+        "http://localhost:8080"
+    )]
+    #[test_case(None, Some("http://config"), "http://config")]
+    #[test_case(Some(""), None, DEFAULT_BASE_URL)]
+    fn resolve_base_url_test(env: Option<&str>, from_config: Option<&str>, expected: &str) {
+        let config = Config {
+            base_url: from_config.map(str::to_string),
+            ..Config::default()
+        };
+        assert_eq!(resolve_base_url(env.map(str::to_string), &config), expected);
+    }
+
+    #[test_case(None, 2023, "data/{day}.input")]
+    #[test_case(None, 2022, "data/{year}/{day}.input")]
+    #[test_case(Some("inputs/{year}-{day}.txt"), 2023, "inputs/{year}-{day}.txt")]
+    fn input_path_test(from_config: Option<&str>, year: u16, expected: &str) {
+        let config = Config {
+            input_path: from_config.map(str::to_string),
+            ..Config::default()
+        };
+        assert_eq!(config.input_path(year), expected);
+    }
+
+    #[test]
+    fn parse_config_test() {
+        let config: Config = toml::from_str(
+            r#"
+            session = "abc"
+            base_url = "http://localhost"
+            input_path = "data/{year}/{day}.input"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost"));
+        assert_eq!(
+            config.input_path.as_deref(),
+            Some("data/{year}/{day}.input")
+        );
+
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
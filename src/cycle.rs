@@ -0,0 +1,226 @@
+//! Finding where simulations start repeating themselves.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// State `start + length` is the same as state `start`, state 0 is the initial one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Steps through a simulation, remembering every state until the first repetition.
+///
+/// States are compared by a key, so parts that don't affect the future (like
+/// counters) can be left out. Once the cycle is found, those parts come from the
+/// earlier state with the same key.
+pub struct Cycles<S, K, F, P> {
+    states: Vec<S>,
+    seen: HashMap<K, usize>,
+    step: F,
+    key: P,
+    cycle: Option<Cycle>,
+}
+
+impl<S, F> Cycles<S, S, F, fn(&S) -> S>
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    /// Compares whole states.
+    pub fn new(initial: S, step: F) -> Self {
+        Self::with_key(initial, step, S::clone)
+    }
+}
+
+impl<S, K, F, P> Cycles<S, K, F, P>
+where
+    K: Hash + Eq,
+    F: FnMut(&S) -> S,
+    P: FnMut(&S) -> K,
+{
+    pub fn with_key(initial: S, step: F, mut key: P) -> Self {
+        Self {
+            seen: HashMap::from([(key(&initial), 0)]),
+            states: vec![initial],
+            step,
+            key,
+            cycle: None,
+        }
+    }
+
+    /// Steps once, returns false once the cycle has been found.
+    fn advance(&mut self) -> bool {
+        if self.cycle.is_some() {
+            return false;
+        }
+
+        let next = (self.step)(self.states.last().expect("Starts with a state"));
+        match self.seen.entry((self.key)(&next)) {
+            Entry::Occupied(entry) => {
+                self.cycle = Some(Cycle {
+                    start: *entry.get(),
+                    length: self.states.len() - entry.get(),
+                });
+                false
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(self.states.len());
+                self.states.push(next);
+                true
+            }
+        }
+    }
+
+    /// Steps until a state repeats, this never returns if none does.
+    pub fn find(&mut self) -> Cycle {
+        while self.advance() {}
+        self.cycle.expect("Only stops at a cycle")
+    }
+
+    /// The state after `n` steps, only simulated until `n` or the first repetition.
+    pub fn nth_state(&mut self, n: usize) -> &S {
+        while self.states.len() <= n && self.advance() {}
+
+        match self.cycle {
+            Some(cycle) => &self.states[cycle.equivalent_step(n)],
+            None => &self.states[n],
+        }
+    }
+}
+
+/// Finds the cycle of a simulation with Brent's algorithm.
+///
+/// Only a few states are kept at a time, at the cost of stepping more often than
+/// [`Cycles`]. States are compared by `key`, like in [`Cycles::with_key`].
+pub fn brent<S: Clone, K: PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // Find the length by letting the hare run ahead in growing powers of two.
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = key(&initial);
+    let mut hare = step(&initial);
+    while tortoise != key(&hare) {
+        if power == length {
+            tortoise = key(&hare);
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead they meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    /// 0, 1, .., 4, then 5, .., 11 over and over.
+    fn step(n: &u64) -> u64 {
+        if *n == 11 {
+            5
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn cycles_test() {
+        let mut cycles = Cycles::new(0, step);
+
+        assert_eq!(
+            cycles.find(),
+            Cycle {
+                start: 5,
+                length: 7
+            }
+        );
+        assert_eq!(*cycles.nth_state(3), 3);
+        assert_eq!(*cycles.nth_state(12), 5);
+        assert_eq!(
+            *cycles.nth_state(1_000_000_000),
+            5 + (1_000_000_000 - 5) % 7
+        );
+    }
+
+    #[test]
+    fn nth_state_before_cycle_test() {
+        let mut steps = 0;
+        let mut cycles = Cycles::new(0, |n| {
+            steps += 1;
+            step(n)
+        });
+
+        assert_eq!(*cycles.nth_state(2), 2);
+        drop(cycles);
+        assert_eq!(steps, 2);
+    }
+
+    #[test]
+    fn with_key_test() {
+        // The counter keeps growing, only the position repeats.
+        let mut cycles =
+            Cycles::with_key((0, 0), |&(n, counter)| (step(&n), counter + 1), |&(n, _)| n);
+
+        assert_eq!(
+            cycles.find(),
+            Cycle {
+                start: 5,
+                length: 7
+            }
+        );
+        assert_eq!(cycles.nth_state(20).0, 6);
+    }
+
+    #[test_case(0, Cycle { start: 5, length: 7 }; "before the cycle")]
+    #[test_case(8, Cycle { start: 0, length: 7 }; "inside the cycle")]
+    fn brent_test(initial: u64, expected: Cycle) {
+        assert_eq!(brent(initial, step, |&n| n), expected);
+
+        let mut cycles = Cycles::new(initial, step);
+        assert_eq!(cycles.find(), expected);
+    }
+
+    #[test]
+    fn brent_self_loop_test() {
+        assert_eq!(
+            brent(3, |&n: &u64| n.min(2), |&n| n),
+            Cycle {
+                start: 1,
+                length: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Answer, Solution};
+use anyhow::{Context, Result};
+
+fn sum_nums(nums: Vec<String>) -> Result<u32> {
+    nums.into_iter()
+        .map(|line| {
+            let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+            let first = digits
+                .next()
+                .with_context(|| format!("There is no digit in {line:?}"))?;
+            let last = digits.next_back().unwrap_or(first);
+
+            Ok(first * 10 + last)
+        })
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::parse_one_per_line::<String>(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(sum_nums(input.clone())?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let num_words = vec![
+            ("one", "one1one"),
+            ("two", "two2two"),
+            ("three", "three3three"),
+            ("four", "four4four"),
+            ("five", "five5five"),
+            ("six", "six6six"),
+            ("seven", "seven7seven"),
+            ("eight", "eight8eight"),
+            ("nine", "nine9nine"),
+        ];
+
+        Ok(sum_nums(
+            input
+                .iter()
+                .map(|line| {
+                    let mut new_line = line.clone();
+                    num_words.clone().into_iter().for_each(|num| {
+                        new_line = new_line.replace(num.0, num.1);
+                    });
+                    new_line
+                })
+                .collect(),
+        )?
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1)]
+    #[test_case(2)]
+    fn blank_line_test(part: u8) {
+        let err = Day1::solve_text("1abc2\n\npqr3stu8vwx\n", part).unwrap_err();
+        assert_eq!(err.to_string(), "There is no digit in \"\"");
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    polygon::Polygon,
+    render::{Canvas, Render, Rgb, Tile},
+    Answer, Direction, Grid, Solution, Vec2D,
+};
+use anyhow::{Context, Error, Result};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    Top2Right,
+    Top2Left,
+    Bottom2Left,
+    Bottom2Right,
+    Ground,
+    StartingPosition,
+}
+
+impl Pipe {
+    /// Direction to leave the pipe in, when entering it going `direction`.
+    fn next(&self, direction: Direction) -> Direction {
+        use Direction::*;
+        use Pipe::*;
+
+        match direction {
+            Up => match self {
+                Vertical => Up,
+                Bottom2Left => Left,
+                Bottom2Right => Right,
+                _ => panic!("Impossible direction: {:?} {:?}", direction, self),
+            },
+            Right => match self {
+                Horizontal => Right,
+                Top2Left => Up,
+                Bottom2Left => Down,
+                _ => panic!("Impossible direction: {:?} {:?}", direction, self),
+            },
+            Down => match self {
+                Vertical => Down,
+                Top2Right => Right,
+                Top2Left => Left,
+                _ => panic!("Impossible direction: {:?} {:?}", direction, self),
+            },
+            Left => match self {
+                Horizontal => Left,
+                Top2Right => Up,
+                Bottom2Right => Down,
+                _ => panic!("Impossible direction: {:?} {:?}", direction, self),
+            },
+        }
+    }
+}
+
+impl FromStr for Pipe {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        use Pipe::*;
+
+        match s {
+            "|" => Ok(Vertical),
+            "-" => Ok(Horizontal),
+            "L" => Ok(Top2Right),
+            "J" => Ok(Top2Left),
+            "7" => Ok(Bottom2Left),
+            "F" => Ok(Bottom2Right),
+            "." => Ok(Ground),
+            "S" => Ok(StartingPosition),
+            _ => Err(Error::msg("Couldn't parse field")),
+        }
+    }
+}
+
+type Field = Grid<Pipe>;
+
+fn starting_directions(
+    starting_position: Vec2D,
+    field: &Field,
+) -> ((Vec2D, Direction), (Vec2D, Direction)) {
+    use Direction::*;
+    use Pipe::*;
+
+    let mut directions = vec![];
+
+    let direction = starting_position + Down.into();
+    if let Some(Vertical | Top2Right | Top2Left) = field.get(direction) {
+        directions.push((direction, Down));
+    }
+
+    let direction = starting_position + Up.into();
+    if let Some(Vertical | Bottom2Right | Bottom2Left) = field.get(direction) {
+        directions.push((direction, Up));
+    }
+
+    let direction = starting_position + Right.into();
+    if let Some(Horizontal | Top2Left | Bottom2Left) = field.get(direction) {
+        directions.push((direction, Right));
+    }
+
+    let direction = starting_position + Left.into();
+    if let Some(Horizontal | Top2Right | Bottom2Right) = field.get(direction) {
+        directions.push((direction, Left));
+    }
+
+    debug_assert!(directions.len() == 2);
+
+    (directions[0], directions[1])
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = (Vec2D, Field);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let field: Field = Grid::parse_with(input, |c| c.to_string().parse())?;
+        let starting_position = field
+            .find(|pipe| *pipe == Pipe::StartingPosition)
+            .context("There is no starting position")?;
+
+        Ok((starting_position, field))
+    }
+
+    fn part1((starting_position, field): &Self::Input) -> Result<Answer> {
+        let starting_position = *starting_position;
+
+        let ((mut left_position, mut left_direction), (mut right_position, mut right_direction)) =
+            starting_directions(starting_position, field);
+
+        let mut distance = 1;
+
+        while left_position != right_position {
+            let left_pipe = field.get(left_position).expect("Should exist");
+            left_direction = left_pipe.next(left_direction);
+            left_position += left_direction.into();
+
+            let right_pipe = field.get(right_position).expect("Should exist");
+            right_direction = right_pipe.next(right_direction);
+            right_position += right_direction.into();
+
+            distance += 1;
+        }
+
+        Ok(distance.into())
+    }
+
+    fn part2((starting_position, field): &Self::Input) -> Result<Answer> {
+        // Every tile of the loop is a vertex, so the enclosed tiles are the points inside.
+        let vertices = find_loop(*starting_position, field);
+
+        Ok(Polygon::new(vertices).interior_points().into())
+    }
+}
+
+/// Every tile of the loop in order, starting with the starting position.
+fn find_loop(starting_position: Vec2D, field: &Field) -> Vec<Vec2D> {
+    let (_, (mut position, mut direction)) = starting_directions(starting_position, field);
+
+    let mut tiles = vec![starting_position];
+    while position != starting_position {
+        tiles.push(position);
+
+        let pipe = field.get(position).expect("Should exist");
+        direction = pipe.next(direction);
+        position += direction.into();
+    }
+
+    tiles
+}
+
+impl Render for Day10 {
+    fn render((starting_position, field): &Self::Input) -> Result<Canvas> {
+        use Pipe::*;
+
+        let canvas = Canvas::new(field, |pipe| {
+            let glyph = match pipe {
+                Vertical => '│',
+                Horizontal => '─',
+                Top2Right => '└',
+                Top2Left => '┘',
+                Bottom2Left => '┐',
+                Bottom2Right => '┌',
+                Ground => '•',
+                StartingPosition => 'S',
+            };
+            Tile::new(glyph, Rgb::GRAY)
+        });
+
+        Ok(canvas
+            .highlight(find_loop(*starting_position, field), Rgb::BLUE)
+            .mark([*starting_position], 'S', Rgb::YELLOW))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let input = Day10::parse(&crate::load_input("data/10_1.sample").unwrap()).unwrap();
+        let canvas = Day10::render(&input).unwrap();
+
+        assert_eq!(canvas.to_text(), "─└│┌┐\n┐S─┐│\n└│┐││\n─└─┘│\n└│─┘┌\n");
+        let highlighted = canvas
+            .tiles()
+            .iter()
+            .filter(|(_, tile)| tile.background == Some(Rgb::BLUE))
+            .count();
+        assert_eq!(highlighted, 8);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    parallel,
+    render::{Canvas, Render, Rgb, Tile},
+    Answer, Grid, Params, Solution, Vec2D,
+};
+use anyhow::{bail, Error, Result};
+use itertools::Itertools;
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Space {
+    EmptySpace,
+    Galaxy,
+}
+
+impl FromStr for Space {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Self::EmptySpace),
+            "#" => Ok(Self::Galaxy),
+            _ => Err(Error::msg("Wrong type of space")),
+        }
+    }
+}
+
+type Image = Grid<Space>;
+
+fn calc_expanded(image: &Image) -> (Vec<i64>, Vec<i64>) {
+    let rows = image
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| !row.contains(&Space::Galaxy))
+        .map(|(y, _)| y as i64)
+        .collect();
+
+    let columns = image
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut column)| {
+            (!column.any(|space| *space == Space::Galaxy)).then_some(x as i64)
+        })
+        .collect();
+
+    (rows, columns)
+}
+
+fn find_galaxies(image: &Image) -> HashSet<Vec2D> {
+    image
+        .iter()
+        .filter(|(_, space)| **space == Space::Galaxy)
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// The expanded distance between two galaxies.
+fn distance(
+    galaxy1: Vec2D,
+    galaxy2: Vec2D,
+    (expanded_rows, expanded_cols): &(Vec<i64>, Vec<i64>),
+    expanded_by: i64,
+) -> i64 {
+    let y_range = if galaxy1.y < galaxy2.y {
+        galaxy1.y as i64..galaxy2.y as i64
+    } else {
+        galaxy2.y as i64..galaxy1.y as i64
+    };
+
+    let x_range = if galaxy1.x < galaxy2.x {
+        galaxy1.x as i64..galaxy2.x as i64
+    } else {
+        galaxy2.x as i64..galaxy1.x as i64
+    };
+
+    let contained_expanded_rows = expanded_rows
+        .iter()
+        .filter(|row| y_range.contains(row))
+        .count() as i64;
+    let contained_expanded_cols = expanded_cols
+        .iter()
+        .filter(|col| x_range.contains(col))
+        .count() as i64;
+
+    galaxy1.manhattan(galaxy2) as i64
+        + (contained_expanded_cols + contained_expanded_rows) * (expanded_by - 1)
+}
+
+/// Sum of the distances from galaxy `i` to every later one, so each pair counts once.
+fn distances_from(
+    galaxies: &[Vec2D],
+    i: usize,
+    expanded: &(Vec<i64>, Vec<i64>),
+    expanded_by: i64,
+) -> i64 {
+    galaxies[i + 1..]
+        .iter()
+        .map(|&galaxy2| distance(galaxies[i], galaxy2, expanded, expanded_by))
+        .sum()
+}
+
+fn solve(image: &Image, expanded_by: i64) -> Result<i64> {
+    let expanded = calc_expanded(image);
+    let galaxies = find_galaxies(image).into_iter().collect_vec();
+
+    Ok(parallel::map(0..galaxies.len(), |i| {
+        distances_from(&galaxies, i, &expanded, expanded_by)
+    })
+    .into_iter()
+    .sum())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, |c| c.to_string().parse())
+    }
+
+    fn part1(image: &Self::Input) -> Result<Answer> {
+        Ok(solve(image, 2)?.into())
+    }
+
+    fn part2(image: &Self::Input) -> Result<Answer> {
+        Ok(solve(image, 1_000_000)?.into())
+    }
+
+    /// `expansion` replaces how many times larger empty rows and columns get.
+    fn solve_with(image: &Self::Input, part: u8, params: &Params) -> Result<Answer> {
+        params.ensure_known(&["expansion"])?;
+        let expanded_by = match part {
+            1 => params.get("expansion", 2),
+            2 => params.get("expansion", 1_000_000),
+            _ => bail!("There is no part {part}"),
+        };
+
+        Ok(solve(image, expanded_by)?.into())
+    }
+}
+
+impl Render for Day11 {
+    fn render(image: &Self::Input) -> Result<Canvas> {
+        let (expanded_rows, expanded_cols) = calc_expanded(image);
+        let expanded = image.positions().filter(|position| {
+            expanded_rows.contains(&(position.y as i64))
+                || expanded_cols.contains(&(position.x as i64))
+        });
+
+        Ok(Canvas::new(image, |space| match space {
+            Space::EmptySpace => Tile::new('•', Rgb::GRAY),
+            Space::Galaxy => Tile::new('#', Rgb::YELLOW),
+        })
+        .highlight(expanded.collect::<Vec<_>>(), Rgb::PURPLE))
+    }
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parallel_test() {
+        let image = Day11::parse(&crate::load_input("data/11.sample").unwrap()).unwrap();
+        let expanded = calc_expanded(&image);
+        let galaxies = find_galaxies(&image).into_iter().collect_vec();
+        let distances = |i| distances_from(&galaxies, i, &expanded, 1_000_000);
+
+        assert_eq!(
+            parallel::map_parallel(0..galaxies.len(), distances),
+            parallel::map_serial(0..galaxies.len(), distances)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{parallel, Answer, Solution};
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpringRow {
+    springs: Vec<Spring>,
+    parity: Vec<u32>,
+}
+
+impl FromStr for SpringRow {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (springs, parity) = s
+            .split_once(' ')
+            .context("Springs and groups should be split by a space")?;
+        let springs = springs
+            .chars()
+            .map(|spring| {
+                Ok(match spring {
+                    '.' => Spring::Operational,
+                    '#' => Spring::Damaged,
+                    '?' => Spring::Unknown,
+                    spring => bail!("Not a valid spring type: `{spring}`"),
+                })
+            })
+            .collect::<Result<_>>()?;
+        let parity = parity
+            .split(',')
+            .map(|num| num.parse::<u32>().context("Groups should be numbers"))
+            .collect::<Result<_>>()?;
+
+        Ok(SpringRow { springs, parity })
+    }
+}
+
+impl SpringRow {
+    /// The row repeated `times` times, joined by unknown springs.
+    fn unfold(&self, times: usize) -> SpringRow {
+        let springs = std::iter::repeat_n(self.springs.as_slice(), times)
+            .collect_vec()
+            .join(&Spring::Unknown);
+        let parity = self.parity.repeat(times);
+
+        SpringRow { springs, parity }
+    }
+}
+
+/// Ways to fill in the springs from `spring` on, with the groups from `group` on left
+/// and `num_done_in_group` damaged springs of the current group seen so far.
+///
+/// `memo` only holds results for this row.
+fn solve_row(
+    row: &SpringRow,
+    spring: usize,
+    group: usize,
+    num_done_in_group: u32,
+    memo: &mut HashMap<(usize, usize, u32), u64>,
+) -> u64 {
+    if spring == row.springs.len() {
+        // The last group may still be open, ended by the end of the row.
+        let done = match num_done_in_group {
+            0 => group == row.parity.len(),
+            num => group + 1 == row.parity.len() && row.parity[group] == num,
+        };
+        return done as u64;
+    }
+    if let Some(&ways) = memo.get(&(spring, group, num_done_in_group)) {
+        return ways;
+    }
+
+    let mut ways = 0;
+
+    let possibles = if row.springs[spring] == Spring::Unknown {
+        vec![Spring::Operational, Spring::Damaged]
+    } else {
+        vec![row.springs[spring]]
+    };
+
+    possibles.into_iter().for_each(|possible| {
+        if possible == Spring::Damaged {
+            ways += solve_row(row, spring + 1, group, num_done_in_group + 1, memo)
+        } else if num_done_in_group > 0 {
+            if group < row.parity.len() && row.parity[group] == num_done_in_group {
+                ways += solve_row(row, spring + 1, group + 1, 0, memo)
+            }
+        } else {
+            ways += solve_row(row, spring + 1, group, 0, memo)
+        }
+    });
+
+    memo.insert((spring, group, num_done_in_group), ways);
+    ways
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<SpringRow>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::parse_one_per_line::<SpringRow>(input)?)
+    }
+
+    fn part1(spring_rows: &Self::Input) -> Result<Answer> {
+        Ok(parallel::map(spring_rows, count_arrangements)
+            .into_iter()
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(spring_rows: &Self::Input) -> Result<Answer> {
+        let spring_rows = spring_rows.iter().map(|row| row.unfold(5)).collect_vec();
+
+        Ok(parallel::map(&spring_rows, count_arrangements)
+            .into_iter()
+            .sum::<u64>()
+            .into())
+    }
+}
+
+fn count_arrangements(row: &SpringRow) -> u64 {
+    solve_row(row, 0, 0, 0, &mut HashMap::new())
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parallel_test() {
+        let input = Day12::parse(&crate::load_input("data/12.sample").unwrap()).unwrap();
+        let rows = input.iter().map(|row| row.unfold(5)).collect_vec();
+
+        assert_eq!(
+            parallel::map_parallel(&rows, count_arrangements),
+            parallel::map_serial(&rows, count_arrangements)
+        );
+    }
+}
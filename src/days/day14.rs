@@ -0,0 +1,183 @@
+use crate::{
+    animate::Animate,
+    cycle::Cycles,
+    render::{Canvas, Render, Rgb, Tile},
+    Answer, Grid, Solution, Vec2D,
+};
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Rock {
+    Round,
+    Cube,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    platform: Grid<Rock>,
+}
+
+impl Platform {
+    /// Rotates the platform clockwise, so the west side faces north.
+    fn spin(self) -> Self {
+        let (width, height) = (self.platform.width(), self.platform.height());
+        let rocks = (0..width)
+            .flat_map(|y| (0..height).map(move |x| Vec2D::new(y as i32, (height - 1 - x) as i32)))
+            .map(|position| self.platform[position])
+            .collect_vec();
+
+        Self {
+            platform: Grid::new(height, width, rocks).expect("Should have the same size"),
+        }
+    }
+
+    /// Rolls all round rocks north.
+    fn tilt(mut self) -> Self {
+        for x in 0..self.platform.width() as i32 {
+            let mut free = 0;
+            for y in 0..self.platform.height() as i32 {
+                match self.platform[Vec2D::new(x, y)] {
+                    Rock::Round => {
+                        self.platform[Vec2D::new(x, y)] = Rock::None;
+                        self.platform[Vec2D::new(x, free)] = Rock::Round;
+                        free += 1;
+                    }
+                    Rock::Cube => free = y + 1,
+                    Rock::None => {}
+                }
+            }
+        }
+
+        self
+    }
+
+    fn cycle(mut self) -> Self {
+        for _ in 0..4 {
+            self = self.tilt().spin();
+        }
+        self
+    }
+}
+
+fn parse_rock(c: char) -> Result<Rock> {
+    match c {
+        'O' => Ok(Rock::Round),
+        '#' => Ok(Rock::Cube),
+        '.' => Ok(Rock::None),
+        _ => bail!("Not a rock"),
+    }
+}
+
+fn calc_load(platform: &Platform) -> u32 {
+    let height = platform.platform.height();
+
+    platform
+        .platform
+        .iter()
+        .filter(|(_, rock)| **rock == Rock::Round)
+        .map(|(position, _)| (height - position.y as usize) as u32)
+        .sum::<u32>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Platform {
+            platform: Grid::parse_with(input, parse_rock)?,
+        })
+    }
+
+    fn part1(platform: &Self::Input) -> Result<Answer> {
+        Ok(calc_load(&platform.clone().tilt()).into())
+    }
+
+    fn part2(platform: &Self::Input) -> Result<Answer> {
+        let mut cycles = Cycles::new(platform.clone(), |platform: &Platform| {
+            platform.clone().cycle()
+        });
+
+        Ok(calc_load(cycles.nth_state(1_000_000_000)).into())
+    }
+}
+
+fn draw(platform: &Platform) -> Canvas {
+    Canvas::new(&platform.platform, |rock| match rock {
+        Rock::Round => Tile::new('O', Rgb::ORANGE),
+        Rock::Cube => Tile::new('#', Rgb::WHITE),
+        Rock::None => Tile::new('.', Rgb::GRAY),
+    })
+}
+
+impl Render for Day14 {
+    /// The platform after tilting it north once.
+    fn render(platform: &Self::Input) -> Result<Canvas> {
+        Ok(draw(&platform.clone().tilt()))
+    }
+}
+
+impl Animate for Day14 {
+    /// Every tilt of the spin cycles until the platform repeats, always with north up.
+    fn frames(platform: &Self::Input) -> Result<Vec<Canvas>> {
+        let cycle = Cycles::new(platform.clone(), |platform: &Platform| {
+            platform.clone().cycle()
+        })
+        .find();
+
+        let mut frames = vec![draw(platform)];
+        let mut current = platform.clone();
+        for _ in 0..cycle.start + cycle.length {
+            for spins in 0..4 {
+                current = current.tilt();
+
+                // Spin the rest of the way around to get north back up.
+                let mut upright = current.clone();
+                for _ in 0..(4 - spins) % 4 {
+                    upright = upright.spin();
+                }
+                frames.push(draw(&upright));
+
+                current = current.spin();
+            }
+        }
+
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frames_test() {
+        let platform = Day14::parse(&crate::load_input("data/14.sample").unwrap()).unwrap();
+        let frames = Day14::frames(&platform).unwrap();
+
+        // The sample repeats after 10 cycles, with 4 tilts each.
+        assert_eq!(frames.len(), 1 + 4 * 10);
+        assert_eq!(frames[1], Day14::render(&platform).unwrap());
+        assert_eq!(
+            frames[4].to_text(),
+            "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{Answer, Solution};
+use std::{collections::BTreeMap, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult, Parser,
+};
+
+#[derive(Debug)]
+pub struct Lens {
+    label: String,
+    operation: Operation,
+}
+
+/// The step of the initialization sequence, like `rn=1`.
+impl Display for Lens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.operation {
+            Operation::Add(focal_length) => write!(f, "{}={focal_length}", self.label),
+            Operation::Remove => write!(f, "{}-", self.label),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add(u32),
+    Remove,
+}
+
+fn hash(text: &str) -> u32 {
+    text.chars().fold(0, |mut curr, c| {
+        curr += c as u32;
+        curr *= 17;
+        curr %= 256;
+        curr
+    })
+}
+
+fn lens(input: &str) -> IResult<&str, Lens> {
+    let (input, lens) = tuple((
+        alpha1::<&str, _>,
+        alt((
+            map(tuple((tag("="), complete::u32)), |(_, number)| {
+                Operation::Add(number)
+            }),
+            map(tag("-"), |_| Operation::Remove),
+        )),
+    ))
+    .map(|(label, operation)| Lens {
+        label: label.to_string(),
+        operation,
+    })
+    .parse(input)?;
+
+    Ok((input, lens))
+}
+
+fn parse_lenses(input: &str) -> IResult<&str, Vec<Lens>> {
+    let (input, lenses) = separated_list1(alt((tag(","), tag("\n"))), lens)(input)?;
+
+    Ok((input, lenses))
+}
+
+fn arrange_lenses(lenses: &[Lens]) -> BTreeMap<u32, Vec<(&str, u32)>> {
+    lenses
+        .iter()
+        .fold(BTreeMap::new(), |mut boxes, Lens { label, operation }| {
+            let label = label.as_str();
+            let box_num = hash(label);
+            match operation {
+                &Operation::Add(focal_length) => {
+                    boxes
+                        .entry(box_num)
+                        .and_modify(|box_lenses| {
+                            if let Some(index) = box_lenses
+                                .iter()
+                                .position(|(inner_label, _)| *inner_label == label)
+                            {
+                                box_lenses[index] = (label, focal_length);
+                            } else {
+                                box_lenses.push((label, focal_length));
+                            }
+                        })
+                        .or_insert(vec![(label, focal_length)]);
+                }
+                Operation::Remove => {
+                    boxes.entry(box_num).and_modify(|box_lenses| {
+                        if let Some(index) = box_lenses
+                            .iter()
+                            .position(|(inner_label, _)| *inner_label == label)
+                        {
+                            box_lenses.remove(index);
+                        }
+                    });
+                }
+            };
+            boxes
+        })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Lens>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, lenses) =
+            parse_lenses(input).map_err(|err| anyhow!("Couldn't parse the lenses: {err}"))?;
+
+        Ok(lenses)
+    }
+
+    fn part1(lenses: &Self::Input) -> Result<Answer> {
+        Ok(lenses
+            .iter()
+            .map(|lens| hash(&lens.to_string()))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(lenses: &Self::Input) -> Result<Answer> {
+        let boxes = arrange_lenses(lenses);
+
+        Ok(boxes
+            .into_iter()
+            .flat_map(|(box_num, box_lenses)| {
+                box_lenses
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(index, (_, focal_length))| {
+                        (1 + box_num) * (index as u32 + 1) * focal_length
+                    })
+            })
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("rn=1", 30 ; "1")]
+    #[test_case("cm-", 253 ; "2")]
+    #[test_case("qp=3", 97 ; "3")]
+    #[test_case("cm=2", 47 ; "4")]
+    #[test_case("qp-", 14 ; "5")]
+    #[test_case("pc=4", 180 ; "6")]
+    #[test_case("ot=9", 9 ; "7")]
+    #[test_case("ab=5", 197 ; "8")]
+    #[test_case("pc-", 48 ; "9")]
+    #[test_case("pc=6", 214 ; "10")]
+    #[test_case("ot=7", 231 ; "11")]
+    fn hash_test(text: &str, hash_value: u32) {
+        assert_eq!(hash(text), hash_value);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    animate::Animate,
+    parallel,
+    render::{Canvas, Render, Rgb, Tile},
+    Answer, Direction, Grid, Solution, Vec2D,
+};
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+#[derive(Clone)]
+pub enum Field {
+    Empty,
+    RightDownMirror,
+    RightUpMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+type Contraption = Grid<Field>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Beam {
+    head: Vec2D,
+    direction: Direction,
+}
+
+impl Beam {
+    fn start() -> Self {
+        Self {
+            head: Vec2D::new(0, 0),
+            direction: Direction::Right,
+        }
+    }
+
+    fn new(head: Vec2D, direction: Direction) -> Self {
+        Self { head, direction }
+    }
+
+    fn next(&mut self) {
+        self.head += self.direction.into();
+    }
+
+    fn rotate(&mut self, field: &Field) {
+        use Direction::*;
+        self.direction = match field {
+            Field::RightDownMirror => match self.direction {
+                Up => Left,
+                Right => Down,
+                Down => Right,
+                Left => Up,
+            },
+            Field::RightUpMirror => match self.direction {
+                Up => Right,
+                Right => Up,
+                Down => Left,
+                Left => Down,
+            },
+            _ => panic!("Shouldn't be passed"),
+        }
+    }
+}
+
+fn parse_field(field: char) -> Result<Field> {
+    Ok(match field {
+        '.' => Field::Empty,
+        '\\' => Field::RightDownMirror,
+        '/' => Field::RightUpMirror,
+        '|' => Field::VerticalSplitter,
+        '-' => Field::HorizontalSplitter,
+        _ => bail!("Not a valid Field"),
+    })
+}
+
+/// Every field the beam passes through.
+fn energized(contraption: &Contraption, starting_beam: Beam) -> HashSet<Vec2D> {
+    propagate(contraption, starting_beam, |_, _| {})
+}
+
+/// Follows the beam until it stops energizing new fields, calling `on_step` with
+/// the current beams and everything visited so far after every step.
+fn propagate(
+    contraption: &Contraption,
+    starting_beam: Beam,
+    mut on_step: impl FnMut(&[Beam], &HashSet<(Vec2D, Direction)>),
+) -> HashSet<Vec2D> {
+    let mut beams = vec![starting_beam];
+    let mut visited = HashSet::new();
+    let mut num_last_visited = 0;
+
+    // Check first field
+    if let Some(field) = contraption.get(beams[0].head) {
+        match field {
+            Field::Empty => {
+                visited.insert((beams[0].head, beams[0].direction));
+            }
+            Field::RightDownMirror => {
+                beams[0].rotate(field);
+                visited.insert((beams[0].head, beams[0].direction));
+            }
+            Field::RightUpMirror => {
+                beams[0].rotate(field);
+                visited.insert((beams[0].head, beams[0].direction));
+            }
+            Field::VerticalSplitter => {
+                if [Direction::Left, Direction::Right].contains(&beams[0].direction) {
+                    beams[0].direction = Direction::Up;
+                    beams.push(Beam::new(beams[0].head, Direction::Down));
+                }
+                visited.insert((beams[0].head, beams[0].direction));
+            }
+            Field::HorizontalSplitter => {
+                if [Direction::Up, Direction::Down].contains(&beams[0].direction) {
+                    beams[0].direction = Direction::Left;
+                    beams.push(Beam::new(beams[0].head, Direction::Right));
+                }
+                visited.insert((beams[0].head, beams[0].direction));
+            }
+        }
+    }
+
+    while num_last_visited < visited.len() {
+        on_step(&beams, &visited);
+        num_last_visited = visited.len();
+        beams = beams
+            .clone()
+            .into_iter()
+            .fold(vec![], |mut beams, mut beam| {
+                beam.next();
+                if let Some(field) = contraption.get(beam.head) {
+                    match field {
+                        Field::Empty => {
+                            visited.insert((beam.head, beam.direction));
+                            beams.push(beam);
+                        }
+                        Field::RightDownMirror => {
+                            beam.rotate(field);
+                            visited.insert((beam.head, beam.direction));
+                            beams.push(beam);
+                        }
+                        Field::RightUpMirror => {
+                            beam.rotate(field);
+                            visited.insert((beam.head, beam.direction));
+                            beams.push(beam);
+                        }
+                        Field::VerticalSplitter => {
+                            if [Direction::Left, Direction::Right].contains(&beam.direction) {
+                                beam.direction = Direction::Up;
+                                beams.push(Beam::new(beam.head, Direction::Down));
+                            }
+                            visited.insert((beam.head, beam.direction));
+                            beams.push(beam);
+                        }
+                        Field::HorizontalSplitter => {
+                            if [Direction::Up, Direction::Down].contains(&beam.direction) {
+                                beam.direction = Direction::Left;
+                                beams.push(Beam::new(beam.head, Direction::Right));
+                            }
+                            visited.insert((beam.head, beam.direction));
+                            beams.push(beam);
+                        }
+                    }
+                };
+                beams
+            });
+    }
+
+    visited.into_iter().map(|(pos, _)| pos).collect()
+}
+
+fn light_contraption(contraption: &Contraption, starting_beam: Beam) -> u32 {
+    energized(contraption, starting_beam).len() as u32
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, parse_field)
+    }
+
+    fn part1(contraption: &Self::Input) -> Result<Answer> {
+        Ok(light_contraption(contraption, Beam::start()).into())
+    }
+
+    fn part2(contraption: &Self::Input) -> Result<Answer> {
+        let lit_fields = parallel::map(edge_beams(contraption), |starting_beam| {
+            light_contraption(contraption, starting_beam)
+        });
+
+        Ok(lit_fields
+            .into_iter()
+            .max()
+            .context("The contraption is empty")?
+            .into())
+    }
+}
+
+/// Every beam entering the contraption from one of its edges.
+fn edge_beams(contraption: &Contraption) -> Vec<Beam> {
+    let width = contraption.width() as i32;
+    let height = contraption.height() as i32;
+
+    (0..width)
+        .map(|x| Beam::new(Vec2D::new(x, 0), Direction::Down))
+        .chain((0..width).map(|x| Beam::new(Vec2D::new(x, height - 1), Direction::Up)))
+        .chain((0..height).map(|y| Beam::new(Vec2D::new(0, y), Direction::Right)))
+        .chain((0..height).map(|y| Beam::new(Vec2D::new(width - 1, y), Direction::Left)))
+        .collect_vec()
+}
+
+fn draw(contraption: &Contraption) -> Canvas {
+    Canvas::new(contraption, |field| {
+        let glyph = match field {
+            Field::Empty => '.',
+            Field::RightDownMirror => '\\',
+            Field::RightUpMirror => '/',
+            Field::VerticalSplitter => '|',
+            Field::HorizontalSplitter => '-',
+        };
+        Tile::new(glyph, Rgb::WHITE)
+    })
+}
+
+impl Render for Day16 {
+    /// The fields energized by the beam of part 1.
+    fn render(contraption: &Self::Input) -> Result<Canvas> {
+        Ok(draw(contraption).highlight(energized(contraption, Beam::start()), Rgb::ORANGE))
+    }
+}
+
+impl Animate for Day16 {
+    /// The beam of part 1 spreading, with the heads of the beams in yellow.
+    fn frames(contraption: &Self::Input) -> Result<Vec<Canvas>> {
+        let background = draw(contraption);
+        let mut frames = vec![];
+        propagate(contraption, Beam::start(), |beams, visited| {
+            frames.push(
+                background
+                    .clone()
+                    .highlight(visited.iter().map(|(position, _)| *position), Rgb::ORANGE)
+                    .highlight(beams.iter().map(|beam| beam.head), Rgb::YELLOW),
+            );
+        });
+
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frames_test() {
+        let contraption = Day16::parse(&crate::load_input("data/16.sample").unwrap()).unwrap();
+        let frames = Day16::frames(&contraption).unwrap();
+
+        let energized = |canvas: &Canvas| {
+            canvas
+                .tiles()
+                .iter()
+                .filter(|(_, tile)| tile.background.is_some())
+                .count()
+        };
+        assert_eq!(energized(&frames[0]), 1);
+        assert!(frames
+            .windows(2)
+            .all(|pair| energized(&pair[0]) <= energized(&pair[1])));
+        assert_eq!(energized(frames.last().unwrap()), 46);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_test() {
+        let contraption = Day16::parse(&crate::load_input("data/16.sample").unwrap()).unwrap();
+        let light = |starting_beam| light_contraption(&contraption, starting_beam);
+
+        assert_eq!(
+            parallel::map_parallel(edge_beams(&contraption), light),
+            parallel::map_serial(edge_beams(&contraption), light)
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    render::{Canvas, Render, Rgb, Tile},
+    search::{dijkstra, Path},
+    Answer, Direction, Grid, Solution, Vec2D,
+};
+use anyhow::{Context, Result};
+
+type BlockMap = Grid<isize>;
+
+/// Where the crucible is and the direction of its last straight run, `None` at the start.
+type State = (Vec2D, Option<Direction>);
+
+fn find_least_heat_loss(
+    blocks: &BlockMap,
+    min_steps: i32,
+    max_steps: i32,
+) -> Option<Path<State, isize>> {
+    let goal = Vec2D::new(blocks.width() as i32 - 1, blocks.height() as i32 - 1);
+
+    let neighbors = |&(position, last): &State| {
+        let mut next = vec![];
+
+        // Every run goes as far as it can, so the next one has to turn.
+        for direction in Direction::ALL {
+            if last.is_some_and(|last| direction == last || direction == last.reverse()) {
+                continue;
+            }
+
+            let mut cost = 0;
+            for steps in 1..=max_steps {
+                let block = position + Vec2D::from(direction) * steps;
+                let Some(heat_loss) = blocks.get(block) else {
+                    break;
+                };
+                cost += heat_loss;
+
+                if steps >= min_steps {
+                    next.push(((block, Some(direction)), cost));
+                }
+            }
+        }
+
+        next
+    };
+
+    dijkstra((Vec2D::ZERO, None), neighbors, |&(position, _)| {
+        position == goal
+    })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = BlockMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, |cost| {
+            Ok(cost.to_digit(10).context("Should be a digit")? as isize)
+        })
+    }
+
+    fn part1(blocks: &Self::Input) -> Result<Answer> {
+        Ok(find_least_heat_loss(blocks, 1, 3)
+            .context("There is no way to the factory")?
+            .cost
+            .into())
+    }
+
+    fn part2(blocks: &Self::Input) -> Result<Answer> {
+        Ok(find_least_heat_loss(blocks, 4, 10)
+            .context("There is no way to the factory")?
+            .cost
+            .into())
+    }
+}
+
+impl Render for Day17 {
+    /// The way of the ultra crucible of part 2.
+    fn render(blocks: &Self::Input) -> Result<Canvas> {
+        let path = find_least_heat_loss(blocks, 4, 10).context("There is no way to the factory")?;
+
+        // The states are the ends of straight runs, fill in the blocks between them.
+        let mut way = vec![Vec2D::ZERO];
+        for &(end, direction) in &path.states[1..] {
+            let step = Vec2D::from(direction.expect("Only the start has no direction"));
+            while way.last() != Some(&end) {
+                way.push(*way.last().unwrap() + step);
+            }
+        }
+
+        Ok(Canvas::new(blocks, |heat_loss| {
+            let glyph = char::from_digit(*heat_loss as u32, 10).unwrap_or('?');
+            let shade = 60 + 20 * *heat_loss as u8;
+            Tile::new(glyph, Rgb(shade, shade, shade))
+        })
+        .highlight(way, Rgb::RED))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let blocks = Day17::parse(&crate::load_input("data/17_2.sample").unwrap()).unwrap();
+        let canvas = Day17::render(&blocks).unwrap();
+
+        // The ultra crucible goes right along the top and then down the last column.
+        let way: Vec<_> = canvas
+            .tiles()
+            .iter()
+            .filter(|(_, tile)| tile.background == Some(Rgb::RED))
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(way.len(), blocks.width() + blocks.height() - 1);
+        assert!(way.contains(&Vec2D::new(7, 0)) && way.contains(&Vec2D::new(11, 4)));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    polygon::Polygon,
+    render::{Canvas, Render, Rgb, Tile},
+    Answer, Direction, Grid, Solution, Vec2D,
+};
+use anyhow::{anyhow, Result};
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{self, newline, one_of, space1},
+    combinator::{map_opt, map_res},
+    multi::separated_list1,
+    sequence::{delimited, tuple},
+    IResult, Parser,
+};
+use num::Zero;
+
+#[derive(Clone)]
+enum HolePart {
+    Edge,
+    Hole,
+}
+
+type DirectionSwapped = (Direction, u32);
+type DiggingInstruction = (Direction, u32, DirectionSwapped);
+type DiggingPlan = Vec<DiggingInstruction>;
+/// The dug out parts, the start of the plan is at `origin`.
+struct Hole {
+    parts: Grid<Option<HolePart>>,
+    origin: Vec2D,
+}
+
+fn hexdigit(input: &str) -> IResult<&str, u32> {
+    map_res(take_while_m_n(1, 5, |c: char| c.is_ascii_hexdigit()), |c| {
+        u32::from_str_radix(c, 16)
+    })
+    .parse(input)
+}
+
+fn direction_swapped(input: &str) -> IResult<&str, DirectionSwapped> {
+    let (input, (distance, direction)) = delimited(
+        tag("(#"),
+        tuple((
+            hexdigit,
+            map_opt(complete::u8, |num| match num {
+                0 => Some(Direction::Right),
+                1 => Some(Direction::Down),
+                2 => Some(Direction::Left),
+                3 => Some(Direction::Up),
+                _ => None,
+            }),
+        )),
+        tag(")"),
+    )(input)?;
+
+    Ok((input, (direction, distance)))
+}
+
+fn instruction(input: &str) -> IResult<&str, DiggingInstruction> {
+    tuple((
+        map_res(one_of("URDL"), Direction::try_from),
+        space1,
+        complete::u32,
+        space1,
+        direction_swapped,
+    ))
+    .map(|(d, _, n, _, c)| (d, n, c))
+    .parse(input)
+}
+
+fn parse_digging_plan(input: &str) -> IResult<&str, DiggingPlan> {
+    let (input, plan) = separated_list1(newline, instruction)(input)?;
+
+    Ok((input, plan))
+}
+
+fn create_hole_boundaries(plan: &DiggingPlan) -> Hole {
+    let mut edges = vec![Vec2D::ZERO];
+    let mut current = Vec2D::ZERO;
+
+    for (direction, distance, _) in plan {
+        (1..=*distance).for_each(|_| {
+            current += (*direction).into();
+            edges.push(current);
+        });
+    }
+
+    let min = Vec2D::new(
+        edges
+            .iter()
+            .map(|edge| edge.x)
+            .min()
+            .expect("Should have min"),
+        edges
+            .iter()
+            .map(|edge| edge.y)
+            .min()
+            .expect("Should have min"),
+    );
+    let max = Vec2D::new(
+        edges
+            .iter()
+            .map(|edge| edge.x)
+            .max()
+            .expect("Should have max"),
+        edges
+            .iter()
+            .map(|edge| edge.y)
+            .max()
+            .expect("Should have max"),
+    );
+    let size = max - min;
+
+    let mut parts = Grid::filled(size.x as usize + 1, size.y as usize + 1, None);
+    for edge in edges {
+        parts[edge - min] = Some(HolePart::Edge);
+    }
+
+    Hole {
+        parts,
+        origin: Vec2D::ZERO - min,
+    }
+}
+
+fn flood_fill(mut hole: Hole) -> Hole {
+    let mut currents = vec![hole.origin + Vec2D::new(1, 1)];
+
+    while let Some(current) = currents.pop() {
+        hole.parts[current] = Some(HolePart::Hole);
+
+        currents.extend(
+            hole.parts
+                .neighbors4(current)
+                .filter(|neighbour| hole.parts[*neighbour].is_none()),
+        );
+    }
+
+    hole
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = DiggingPlan;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, plan) = parse_digging_plan(input)
+            .map_err(|err| anyhow!("Couldn't parse the digging plan: {err}"))?;
+
+        Ok(plan)
+    }
+
+    fn part1(plan: &Self::Input) -> Result<Answer> {
+        let hole = create_hole_boundaries(plan);
+        let hole = flood_fill(hole);
+
+        let dug_out = hole.parts.iter().filter(|(_, part)| part.is_some()).count();
+        Ok((dug_out as u32).into())
+    }
+
+    fn part2(plan: &Self::Input) -> Result<Answer> {
+        let hole = Polygon::<i64>::from_moves(
+            Vec2D::zero(),
+            plan.iter()
+                .map(|(_, _, (direction, distance))| (*direction, *distance as i64)),
+        );
+
+        Ok(hole.lattice_points().into())
+    }
+}
+
+impl Render for Day18 {
+    /// The hole dug by the plan of part 1, its start marked with `S`.
+    fn render(plan: &Self::Input) -> Result<Canvas> {
+        let hole = flood_fill(create_hole_boundaries(plan));
+
+        Ok(Canvas::new(&hole.parts, |part| match part {
+            Some(HolePart::Edge) => Tile::new('#', Rgb::ORANGE),
+            Some(HolePart::Hole) => Tile::new('#', Rgb::GRAY),
+            None => Tile::new(' ', Rgb::BLACK),
+        })
+        .mark([hole.origin], 'S', Rgb::YELLOW))
+    }
+}
@@ -0,0 +1,376 @@
+use crate::{
+    dot::{Dot, Graph},
+    interval::IntervalSet,
+    Answer, Solution,
+};
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    fall_through: Next,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    expr: Expr,
+    next: Next,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    GT(char, u32),
+    ST(char, u32),
+}
+
+impl Expr {
+    fn eval(&self, part: Part) -> bool {
+        match self {
+            Expr::GT(c, val) => {
+                let cmp = match c {
+                    'x' => part.x,
+                    'm' => part.m,
+                    'a' => part.a,
+                    's' => part.s,
+                    _ => unreachable!(),
+                };
+                cmp > *val
+            }
+            Expr::ST(c, val) => {
+                let cmp = match c {
+                    'x' => part.x,
+                    'm' => part.m,
+                    'a' => part.a,
+                    's' => part.s,
+                    _ => unreachable!(),
+                };
+                cmp < *val
+            }
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::GT(c, val) => write!(f, "{c}>{val}"),
+            Expr::ST(c, val) => write!(f, "{c}<{val}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Next {
+    Rejected,
+    Accepted,
+    Workflow(String),
+}
+
+impl Display for Next {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Next::Rejected => write!(f, "R"),
+            Next::Accepted => write!(f, "A"),
+            Next::Workflow(workflow) => write!(f, "{workflow}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+#[derive(Debug, Clone)]
+struct RangePart {
+    parts: BTreeMap<char, IntervalSet<u32>>,
+}
+
+impl RangePart {
+    fn new(
+        x: RangeInclusive<u32>,
+        m: RangeInclusive<u32>,
+        a: RangeInclusive<u32>,
+        s: RangeInclusive<u32>,
+    ) -> Self {
+        let mut parts = BTreeMap::new();
+        parts.insert('x', x.into());
+        parts.insert('m', m.into());
+        parts.insert('a', a.into());
+        parts.insert('s', s.into());
+
+        Self { parts }
+    }
+
+    /// Splits into the parts matching `expr` and the ones that don't.
+    fn split(&self, expr: &Expr) -> (RangePart, RangePart) {
+        let (param, matching, not_matching) = match *expr {
+            Expr::GT(param, val) => {
+                let (below, above) = self.parts[&param].split_at(val + 1);
+                (param, above, below)
+            }
+            Expr::ST(param, val) => {
+                let (below, above) = self.parts[&param].split_at(val);
+                (param, below, above)
+            }
+        };
+        let with = |values| {
+            let mut range = self.clone();
+            range.parts.insert(param, values);
+            range
+        };
+
+        (with(matching), with(not_matching))
+    }
+
+    fn count(&self) -> u64 {
+        self.parts
+            .values()
+            .map(|values| values.len() as u64)
+            .product()
+    }
+}
+
+fn parse_next(next: &str) -> Next {
+    match next {
+        "A" => Next::Accepted,
+        "R" => Next::Rejected,
+        workflow => Next::Workflow(workflow.to_string()),
+    }
+}
+
+/// One of the ratings `x`, `m`, `a` or `s` compared to a number, like `a<2006`.
+fn parse_expr(expr: &str) -> Result<Expr> {
+    let (param, val, expr_type): (_, _, fn(char, u32) -> Expr) = match expr.split_once('>') {
+        Some((param, val)) => (param, val, Expr::GT),
+        None => {
+            let (param, val) = expr.split_once('<').context("Should be > or <")?;
+            (param, val, Expr::ST)
+        }
+    };
+    let param = match param {
+        "x" => 'x',
+        "m" => 'm',
+        "a" => 'a',
+        "s" => 's',
+        param => bail!("Not a rating: {param}"),
+    };
+
+    Ok(expr_type(
+        param,
+        val.parse::<u32>().context("Should be a number")?,
+    ))
+}
+
+fn parse_input(input: &str) -> Result<(BTreeMap<String, Workflow>, Vec<Part>)> {
+    let (workflows, parts) = input.split_once("\n\n").context("Should be well formed")?;
+
+    let workflows = workflows
+        .lines()
+        .map(|line| {
+            let (name, rules) = line
+                .strip_suffix('}')
+                .and_then(|line| line.split_once('{'))
+                .context("Should be well formed")?;
+            let mut rules: Vec<&str> = rules.split(',').collect();
+
+            let fall_through = parse_next(rules.pop().context("Should have last")?);
+
+            let rules = rules
+                .into_iter()
+                .map(|rule| {
+                    let (expr, next) = rule.split_once(':').context("Should be well formed")?;
+
+                    Ok(Rule {
+                        expr: parse_expr(expr)?,
+                        next: parse_next(next),
+                    })
+                })
+                .collect::<Result<_>>()?;
+
+            Ok((
+                name.to_string(),
+                Workflow {
+                    rules,
+                    fall_through,
+                },
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    let parts = parts
+        .lines()
+        .map(|part_txt| {
+            let mut part = Part {
+                x: 0,
+                m: 0,
+                a: 0,
+                s: 0,
+            };
+
+            let part_txt = part_txt
+                .strip_prefix('{')
+                .and_then(|part_txt| part_txt.strip_suffix('}'))
+                .context("Part should be in braces")?;
+
+            for part_part in part_txt.split(',') {
+                let (param, val) = part_part.split_once('=').context("Should be well formed")?;
+                let val = val.parse::<u32>().context("Should be a number")?;
+
+                match param {
+                    "x" => part.x = val,
+                    "m" => part.m = val,
+                    "a" => part.a = val,
+                    "s" => part.s = val,
+                    param => bail!("Not a rating: {param}"),
+                }
+            }
+
+            Ok(part)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((workflows, parts))
+}
+
+fn execute_workflow(part: Part, workflow: Workflow) -> Next {
+    let mut next = None;
+    let mut rule_index = 0;
+    let rules = workflow.rules;
+
+    while next.is_none() {
+        if rule_index == rules.len() {
+            next = Some(workflow.fall_through.clone());
+            break;
+        }
+        let rule = &rules[rule_index];
+        if rule.expr.eval(part.clone()) {
+            next = Some(rule.next.clone());
+        }
+        rule_index += 1;
+    }
+
+    if let Some(next) = next {
+        return next;
+    }
+
+    Next::Rejected
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (BTreeMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((worklows, parts): &Self::Input) -> Result<Answer> {
+        Ok(parts
+            .iter()
+            .filter(|part| {
+                let mut next = Next::Workflow("in".to_string());
+
+                while let Next::Workflow(current) = next {
+                    let workflow = worklows.get(&current).expect("Should exist");
+
+                    next = execute_workflow((*part).clone(), workflow.clone());
+                }
+
+                matches!(next, Next::Accepted)
+            })
+            .map(|part| part.x + part.m + part.a + part.s)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2((worklows, _): &Self::Input) -> Result<Answer> {
+        let mut ranges = vec![(
+            "in".to_string(),
+            0,
+            RangePart::new(1u32..=4000, 1u32..=4000, 1u32..=4000, 1u32..=4000),
+        )];
+
+        let mut accepted = vec![];
+
+        while let Some(range) = ranges.pop() {
+            let workflow = worklows.get(&range.0).expect("Should exist");
+
+            if range.1 == workflow.rules.len() {
+                match &workflow.fall_through {
+                    Next::Rejected => (),
+                    Next::Accepted => accepted.push(range.2),
+                    Next::Workflow(workflow_ident) => {
+                        ranges.push((workflow_ident.to_string(), 0, range.2))
+                    }
+                }
+            } else {
+                let rule = &workflow.rules[range.1];
+                let (matching_range, not_matching_range) = range.2.split(&rule.expr);
+                ranges.push((range.0, range.1 + 1, not_matching_range));
+
+                match &rule.next {
+                    Next::Rejected => (),
+                    Next::Accepted => accepted.push(matching_range),
+                    Next::Workflow(workflow_ident) => {
+                        ranges.push((workflow_ident.to_string(), 0, matching_range))
+                    }
+                }
+            }
+        }
+
+        Ok(accepted.iter().map(RangePart::count).sum::<u64>().into())
+    }
+}
+
+impl Dot for Day19 {
+    /// The workflows with their rules as edges, labeled with the condition.
+    fn graph((workflows, _): &Self::Input) -> Result<Graph> {
+        let mut graph = Graph::directed();
+        graph
+            .node("in", &[("shape", "box")])
+            .node(
+                Next::Accepted,
+                &[("shape", "doublecircle"), ("color", "green")],
+            )
+            .node(
+                Next::Rejected,
+                &[("shape", "doublecircle"), ("color", "red")],
+            );
+        for (name, workflow) in workflows {
+            for rule in &workflow.rules {
+                graph.edge(name, &rule.next, &[("label", &rule.expr.to_string())]);
+            }
+            graph.edge(name, &workflow.fall_through, &[("label", "else")]);
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn graph_test() {
+        let input = Day19::parse(&crate::load_input("data/19.sample").unwrap()).unwrap();
+        let dot = Day19::graph(&input).unwrap().to_dot();
+
+        assert!(dot.contains(
+            "  \"in\" -> \"px\" [label=\"s<1351\"]\n  \"in\" -> \"qqz\" [label=\"else\"]\n"
+        ));
+        assert!(dot.contains(
+            "  \"crn\" -> \"A\" [label=\"x>2662\"]\n  \"crn\" -> \"R\" [label=\"else\"]\n"
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Answer, Solution};
+use anyhow::{bail, Context, Result};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let (id, colors) = s.split_once(':').context("Should be well formed")?;
+
+        let id: u32 = id
+            .split(' ')
+            .nth(1)
+            .context("Should be Id")?
+            .parse()
+            .context("Id should be a number")?;
+
+        let mut game = Game {
+            id,
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        for color in colors.split(&[';', ',']) {
+            let mut color = color.trim_start().split(' ');
+            let num = color
+                .next()
+                .context("Color number should exist")?
+                .parse::<u32>()
+                .context("Color number should be a number")?;
+            let color_name = color.next().context("Color name should exist")?;
+
+            match color_name {
+                "red" => {
+                    if game.red < num {
+                        game.red = num;
+                    }
+                }
+                "green" => {
+                    if game.green < num {
+                        game.green = num;
+                    }
+                }
+                "blue" => {
+                    if game.blue < num {
+                        game.blue = num;
+                    }
+                }
+                _ => bail!("Undefined color {color_name}"),
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::parse_one_per_line::<Game>(input)?)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer> {
+        Ok(games
+            .iter()
+            .filter(|game| game.red <= 12 && game.green <= 13 && game.blue <= 14)
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer> {
+        Ok(games
+            .iter()
+            .map(|game| game.red * game.green * game.blue)
+            .sum::<u32>()
+            .into())
+    }
+}
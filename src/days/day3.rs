@@ -0,0 +1,137 @@
+use crate::{Answer, Grid, Solution, Vec2D};
+use anyhow::Result;
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub enum Field {
+    Number(u32),
+    Symbol(char),
+    Empty,
+}
+
+type Schematic = Grid<Field>;
+
+fn parse_field(c: char) -> Result<Field> {
+    Ok(match c {
+        '.' => Field::Empty,
+        c if c.is_ascii_digit() => Field::Number(c.to_digit(10).expect("Should be a digit")),
+        c => Field::Symbol(c),
+    })
+}
+
+fn calc_numbers(schematic: &Schematic) -> Vec<Vec<(Vec2D, u32)>> {
+    schematic.iter().fold(
+        vec![],
+        |mut numbers: Vec<Vec<(Vec2D, u32)>>, (position, field)| {
+            if let Field::Number(num) = field {
+                match numbers.last_mut() {
+                    Some(number)
+                        if number.last().map(|(last, _)| *last + Vec2D::RIGHT)
+                            == Some(position) =>
+                    {
+                        number.push((position, *num))
+                    }
+                    _ => numbers.push(vec![(position, *num)]),
+                }
+            }
+            numbers
+        },
+    )
+}
+
+fn get_positions_to_check(schematic: &Schematic, list: &[(Vec2D, u32)]) -> Vec<Vec2D> {
+    let num_positions: Vec<&Vec2D> = list.iter().map(|(position, _)| position).collect();
+    list.iter()
+        .flat_map(|(pos, _)| schematic.neighbors8(*pos))
+        .unique()
+        .filter(|num| !num_positions.contains(&num))
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, parse_field)
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<Answer> {
+        let numbers = calc_numbers(schematic);
+
+        let parts_sum = numbers
+            .into_iter()
+            .filter_map(|num_list| {
+                let pos_to_check = get_positions_to_check(schematic, &num_list);
+
+                let is_part_number = pos_to_check
+                    .iter()
+                    .any(|pos| matches!(schematic.get(*pos), Some(Field::Symbol(_))));
+
+                if is_part_number {
+                    Some(
+                        num_list
+                            .iter()
+                            .map(|(_, num)| num.to_string())
+                            .collect::<String>()
+                            .parse::<u32>()
+                            .unwrap(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .sum::<u32>();
+
+        Ok(parts_sum.into())
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<Answer> {
+        let numbers = calc_numbers(schematic);
+
+        let gear_ratios = schematic
+            .iter()
+            .filter(|(_, field)| matches!(field, Field::Symbol('*')))
+            .filter_map(|(position, _)| {
+                let pos_to_check = schematic.neighbors8(position).collect_vec();
+
+                let number_indexes = pos_to_check
+                    .iter()
+                    .fold(vec![], |mut number_indexes, pos| {
+                        numbers.iter().enumerate().for_each(|(i, num_list)| {
+                            if num_list.iter().any(|num_pos| num_pos.0 == *pos) {
+                                number_indexes.push(i);
+                            }
+                        });
+                        number_indexes
+                    })
+                    .into_iter()
+                    .unique()
+                    .collect_vec();
+
+                if number_indexes.len() == 2 {
+                    Some(
+                        number_indexes
+                            .iter()
+                            .map(|index| {
+                                numbers[*index]
+                                    .iter()
+                                    .map(|(_, num)| num.to_string())
+                                    .collect::<String>()
+                                    .parse::<usize>()
+                                    .expect("Should be a number")
+                            })
+                            .product::<usize>(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>();
+
+        Ok(gear_ratios.into())
+    }
+}
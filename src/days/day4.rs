@@ -0,0 +1,92 @@
+use crate::{Answer, Solution};
+use anyhow::{Context, Result};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Card {
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn calc_score(&self) -> u32 {
+        let matches = self.matches();
+
+        // 1 Point for the first match, double for every other
+        // so all over one are 2^(match - 1)
+        match matches {
+            0 => 0,
+            1 => 1,
+            x => u32::pow(2, x - 1),
+        }
+    }
+
+    fn matches(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count() as u32
+    }
+}
+
+impl FromStr for Card {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let (_, numbers) = s.split_once(':').context("Should be well formed")?;
+        let (winning_numbers, numbers) =
+            numbers.split_once('|').context("Should be well formed")?;
+
+        Ok(Card {
+            winning_numbers: parse_numbers(winning_numbers)?,
+            numbers: parse_numbers(numbers)?,
+        })
+    }
+}
+
+fn parse_numbers(numbers: &str) -> Result<Vec<u32>> {
+    numbers
+        .split_ascii_whitespace()
+        .map(|num| num.parse::<u32>().context("Should be a number"))
+        .collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::parse_one_per_line::<Card>(input)?)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Answer> {
+        Ok(cards
+            .iter()
+            .map(|card| card.calc_score())
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Answer> {
+        let cards = cards.iter().enumerate();
+
+        let mut total_cards = cards.clone().map(|_| 1).collect::<Vec<u32>>();
+
+        cards.clone().for_each(|(i, card)| {
+            let matches = card.matches();
+            let card_total = total_cards[i];
+            let next_index = i + 1;
+
+            (next_index..usize::min(cards.len(), next_index + (matches as usize))).for_each(
+                |index| {
+                    total_cards[index] += card_total;
+                },
+            );
+        });
+
+        Ok(total_cards.into_iter().sum::<u32>().into())
+    }
+}
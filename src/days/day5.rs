@@ -0,0 +1,155 @@
+use crate::{interval::IntervalSet, Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{self, newline, space0, space1},
+    multi::{many1, separated_list1},
+    sequence::{pair, terminated, tuple},
+    IResult, Parser,
+};
+use nom_supreme::error::ErrorTree;
+use std::ops::Range;
+
+#[derive(Clone)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+#[derive(Clone)]
+struct Map {
+    ranges: Vec<MapRange>,
+}
+
+#[derive(Clone)]
+struct MapRange {
+    source_range: Range<i64>,
+    destination_range: Range<i64>,
+}
+
+impl Map {
+    fn translate(&self, source: i64) -> i64 {
+        let valid_mapping = self
+            .ranges
+            .iter()
+            .find(|MapRange { source_range, .. }| source_range.contains(&source));
+
+        let Some(MapRange {
+            source_range,
+            destination_range,
+        }) = valid_mapping
+        else {
+            return source;
+        };
+
+        let offset = source - source_range.start;
+
+        destination_range.start + offset
+    }
+
+    /// Maps every value in `sources` at once, values not covered by a range stay the same.
+    fn translate_all(&self, sources: &IntervalSet<i64>) -> IntervalSet<i64> {
+        sources.map(self.ranges.iter().map(
+            |MapRange {
+                 source_range,
+                 destination_range,
+             }| {
+                (
+                    source_range.clone(),
+                    destination_range.start - source_range.start,
+                )
+            },
+        ))
+    }
+}
+
+fn range(input: &str) -> IResult<&str, MapRange, ErrorTree<&str>> {
+    let (input, (destination_start, _, source_start, _, count)) =
+        tuple((complete::i64, space1, complete::i64, space1, complete::i64))(input)?;
+
+    Ok((
+        input,
+        MapRange {
+            source_range: source_start..(source_start + count),
+            destination_range: destination_start..(destination_start + count),
+        },
+    ))
+}
+
+fn map(input: &str) -> IResult<&str, Vec<MapRange>, ErrorTree<&str>> {
+    let (input, _) = pair(take_until("\n"), newline)(input)?;
+    let (input, map) = separated_list1(newline, range).parse(input)?;
+
+    Ok((input, map))
+}
+
+fn maps(input: &str) -> IResult<&str, Vec<Map>, ErrorTree<&str>> {
+    let (input, maps) = separated_list1(pair(newline, newline), map)(input)?;
+
+    Ok((
+        input,
+        maps.into_iter().map(|ranges| Map { ranges }).collect(),
+    ))
+}
+
+fn parse_almanac(input: &str) -> IResult<&str, Almanac, ErrorTree<&str>> {
+    let (input, _) = tag("seeds: ")(input)?;
+    let (input, seeds) = many1(terminated(complete::i64, space0))(input)?;
+    let (input, _) = tuple((newline, newline))(input)?;
+    let (input, maps) = maps(input)?;
+
+    Ok((input, Almanac { seeds, maps }))
+}
+
+/// In part 2 the seeds are pairs of range starts and lengths.
+fn seed_ranges(seeds: &[i64]) -> Result<IntervalSet<i64>> {
+    if !seeds.len().is_multiple_of(2) {
+        bail!("The seeds should be pairs of start and length");
+    }
+
+    Ok(seeds
+        .chunks_exact(2)
+        .map(|pair| pair[0]..(pair[0] + pair[1]))
+        .collect())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, almanac) =
+            parse_almanac(input).map_err(|err| anyhow!("Couldn't parse the almanac: {err}"))?;
+
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer> {
+        let location = almanac
+            .seeds
+            .iter()
+            .map(|seed| {
+                almanac
+                    .maps
+                    .iter()
+                    .fold(*seed, |num, map| map.translate(num))
+            })
+            .min();
+
+        Ok(location.context("No min")?.into())
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer> {
+        let locations = almanac
+            .maps
+            .iter()
+            .fold(seed_ranges(&almanac.seeds)?, |ranges, map| {
+                map.translate_all(&ranges)
+            });
+
+        Ok(locations.min().context("No min")?.into())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use nom::{
+    character::complete,
+    character::complete::{newline, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use std::iter::zip;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+fn parse_races(input: &str) -> IResult<&str, Vec<Race>> {
+    let (input, races) = separated_pair(
+        tag("Time:")
+            .precedes(space1)
+            .precedes(separated_list1(space1, complete::u64)),
+        newline,
+        tag("Distance:")
+            .precedes(space1)
+            .precedes(separated_list1(space1, complete::u64)),
+    )
+    .map(|(times, record_distances)| {
+        zip(times, record_distances)
+            .map(|(time, record_distance)| Race {
+                time,
+                record_distance,
+            })
+            .collect::<Vec<Race>>()
+    })
+    .parse(input)?;
+
+    Ok((input, races))
+}
+
+/// In part 2 the numbers of all races are actually the digits of a single race.
+fn single_race(races: &[Race]) -> Race {
+    let time = races
+        .iter()
+        .map(|race| race.time.to_string())
+        .collect::<String>()
+        .parse::<u64>()
+        .expect("Should be a u64");
+    let record_distance = races
+        .iter()
+        .map(|race| race.record_distance.to_string())
+        .collect::<String>()
+        .parse::<u64>()
+        .expect("Should be a u64");
+
+    Race {
+        time,
+        record_distance,
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, races) =
+            parse_races(input).map_err(|err| anyhow!("Couldn't parse the races: {err}"))?;
+
+        Ok(races)
+    }
+
+    fn part1(races: &Self::Input) -> Result<Answer> {
+        let result = races
+            .iter()
+            .map(|race| {
+                (1..(race.time - 1))
+                    .filter_map(|accelerated_time| {
+                        let distance = (race.time - accelerated_time) * accelerated_time;
+
+                        if distance > race.record_distance {
+                            Some(distance)
+                        } else {
+                            None
+                        }
+                    })
+                    .count() as u64
+            })
+            .product::<u64>();
+
+        Ok(result.into())
+    }
+
+    fn part2(races: &Self::Input) -> Result<Answer> {
+        let race = single_race(races);
+        let ways_to_win = (1..(race.time - 1))
+            .filter_map(|accelerated_time| {
+                let distance = (race.time - accelerated_time) * accelerated_time;
+
+                if distance > race.record_distance {
+                    Some(distance)
+                } else {
+                    None
+                }
+            })
+            .count() as u64;
+
+        Ok(ways_to_win.into())
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use nom::{
+    character::complete,
+    character::complete::{newline, one_of, space0},
+    combinator::{consumed, map_res},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use nom_supreme::error::ErrorTree;
+use std::{collections::BTreeMap, fmt::Display};
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Hand {
+    value: HandValue,
+    bid: u32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct HandWithJoker {
+    value: HandValueWithJoker,
+    bid: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum HandValue {
+    HighCard([Card; 5]),
+    OnePair([Card; 5]),
+    TwoPair([Card; 5]),
+    ThreeOfAKind([Card; 5]),
+    FullHouse([Card; 5]),
+    FourOfAKind([Card; 5]),
+    FiveOfAKind([Card; 5]),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum HandValueWithJoker {
+    HighCard([CardWithJoker; 5]),
+    OnePair([CardWithJoker; 5]),
+    TwoPair([CardWithJoker; 5]),
+    ThreeOfAKind([CardWithJoker; 5]),
+    FullHouse([CardWithJoker; 5]),
+    FourOfAKind([CardWithJoker; 5]),
+    FiveOfAKind([CardWithJoker; 5]),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+enum CardWithJoker {
+    J,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    Q,
+    K,
+    A,
+}
+
+/// Cards that don't make up a hand of five, like `32T3KK`.
+#[derive(Debug)]
+struct InvalidHand(String);
+
+impl Display for InvalidHand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} isn't a hand of five cards", self.0)
+    }
+}
+
+impl std::error::Error for InvalidHand {}
+
+fn hand_value(input: &str) -> IResult<&str, HandValue, ErrorTree<&str>> {
+    let cards = many1(one_of("AKQJT998765432").map(|card_value| match card_value {
+        'A' => Card::A,
+        'K' => Card::K,
+        'Q' => Card::Q,
+        'J' => Card::J,
+        'T' => Card::T,
+        '9' => Card::Nine,
+        '8' => Card::Eight,
+        '7' => Card::Seven,
+        '6' => Card::Six,
+        '5' => Card::Five,
+        '4' => Card::Four,
+        '3' => Card::Three,
+        '2' => Card::Two,
+        _ => panic!("Shouldn't happen"),
+    }));
+
+    map_res(consumed(cards), |(text, cards): (&str, Vec<Card>)| {
+        let invalid = || InvalidHand(text.to_string());
+        let hand: [Card; 5] = cards.clone().try_into().map_err(|_| invalid())?;
+
+        let grouped_cards = cards.into_iter().fold(BTreeMap::new(), |mut acc, card| {
+            acc.entry(card).and_modify(|num| *num += 1).or_insert(1);
+            acc
+        });
+
+        Ok(match (grouped_cards.len(), grouped_cards.values().max()) {
+            (1, _) => HandValue::FiveOfAKind(hand),
+            (2, Some(4)) => HandValue::FourOfAKind(hand),
+            (2, Some(3)) => HandValue::FullHouse(hand),
+            (3, Some(3)) => HandValue::ThreeOfAKind(hand),
+            (3, Some(2)) => HandValue::TwoPair(hand),
+            (4, _) => HandValue::OnePair(hand),
+            (5, _) => HandValue::HighCard(hand),
+            _ => return Err(invalid()),
+        })
+    })
+    .parse(input)
+}
+
+fn hand(input: &str) -> IResult<&str, Hand, ErrorTree<&str>> {
+    separated_pair(hand_value, space0, complete::u32)
+        .map(|(value, bid)| Hand { value, bid })
+        .parse(input)
+}
+
+fn parse_hands(input: &str) -> IResult<&str, Vec<Hand>, ErrorTree<&str>> {
+    separated_list1(newline, hand).parse(input)
+}
+
+fn hand_value_with_jokers(input: &str) -> IResult<&str, HandValueWithJoker, ErrorTree<&str>> {
+    let cards = many1(one_of("AKQJT998765432").map(|card_value| match card_value {
+        'A' => CardWithJoker::A,
+        'K' => CardWithJoker::K,
+        'Q' => CardWithJoker::Q,
+        'J' => CardWithJoker::J,
+        'T' => CardWithJoker::T,
+        '9' => CardWithJoker::Nine,
+        '8' => CardWithJoker::Eight,
+        '7' => CardWithJoker::Seven,
+        '6' => CardWithJoker::Six,
+        '5' => CardWithJoker::Five,
+        '4' => CardWithJoker::Four,
+        '3' => CardWithJoker::Three,
+        '2' => CardWithJoker::Two,
+        _ => panic!("Shouldn't happen"),
+    }));
+
+    map_res(
+        consumed(cards),
+        |(text, cards): (&str, Vec<CardWithJoker>)| {
+            let invalid = || InvalidHand(text.to_string());
+            let hand: [CardWithJoker; 5] = cards.clone().try_into().map_err(|_| invalid())?;
+
+            let mut grouped_cards = cards.into_iter().fold(BTreeMap::new(), |mut acc, card| {
+                acc.entry(card).and_modify(|num| *num += 1).or_insert(1);
+                acc
+            });
+
+            if let Some(num_jokers) = grouped_cards.clone().get(&CardWithJoker::J) {
+                if *num_jokers != 5 {
+                    grouped_cards.remove(&CardWithJoker::J);
+
+                    let (max_key, _) = grouped_cards.clone().into_iter().fold(
+                        (CardWithJoker::Two, 0),
+                        |mut acc, card| {
+                            if card.1 > acc.1 {
+                                acc = card
+                            }
+                            acc
+                        },
+                    );
+
+                    grouped_cards
+                        .entry(max_key)
+                        .and_modify(|num| *num += num_jokers);
+                }
+            }
+
+            Ok(match (grouped_cards.len(), grouped_cards.values().max()) {
+                (1, _) => HandValueWithJoker::FiveOfAKind(hand),
+                (2, Some(4)) => HandValueWithJoker::FourOfAKind(hand),
+                (2, Some(3)) => HandValueWithJoker::FullHouse(hand),
+                (3, Some(3)) => HandValueWithJoker::ThreeOfAKind(hand),
+                (3, Some(2)) => HandValueWithJoker::TwoPair(hand),
+                (4, _) => HandValueWithJoker::OnePair(hand),
+                (5, _) => HandValueWithJoker::HighCard(hand),
+                _ => return Err(invalid()),
+            })
+        },
+    )
+    .parse(input)
+}
+
+fn hand_with_jokers(input: &str) -> IResult<&str, HandWithJoker, ErrorTree<&str>> {
+    separated_pair(hand_value_with_jokers, space0, complete::u32)
+        .map(|(value, bid)| HandWithJoker { value, bid })
+        .parse(input)
+}
+
+fn parse_hands_with_jokers(input: &str) -> IResult<&str, Vec<HandWithJoker>, ErrorTree<&str>> {
+    separated_list1(newline, hand_with_jokers).parse(input)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    // The jokers change how hands are parsed, so both variants are parsed upfront
+    type Input = (Vec<Hand>, Vec<HandWithJoker>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, hands) =
+            parse_hands(input).map_err(|err| anyhow!("Couldn't parse the hands: {err}"))?;
+        let (_, hands_with_jokers) = parse_hands_with_jokers(input)
+            .map_err(|err| anyhow!("Couldn't parse the hands: {err}"))?;
+
+        Ok((hands, hands_with_jokers))
+    }
+
+    fn part1((hands, _): &Self::Input) -> Result<Answer> {
+        let mut hands = hands.clone();
+
+        hands.sort();
+
+        Ok(hands
+            .into_iter()
+            .enumerate()
+            .map(|(i, hand)| hand.bid * (i as u32 + 1))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2((_, hands): &Self::Input) -> Result<Answer> {
+        let mut hands = hands.clone();
+
+        hands.sort();
+
+        Ok(hands
+            .into_iter()
+            .enumerate()
+            .map(|(i, hand)| hand.bid * (i as u32 + 1))
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::{Card::*, HandValue::*};
+    use test_case::test_case;
+
+    #[test_case(FiveOfAKind([A, A, A, A, A]),
+                FourOfAKind([A, A, A, A, K])
+                ; "Simple ordering by type")]
+    #[test_case(FiveOfAKind([A, A, A, A, A]),
+                FiveOfAKind([A, A, A, A, K])
+                ; "Order by highest first value")]
+    #[test_case(ThreeOfAKind([Two, Four, Four, Four, A]),
+                ThreeOfAKind([Two, Two, Two, Three, K])
+                ; "Order by highest first value, sanity check")]
+    fn hands_order_test(a: HandValue, b: HandValue) {
+        assert!(a > b);
+    }
+
+    #[test_case(A, K ; "Ace larger than King")]
+    #[test_case(J, Five ; "Jack larger than ten")]
+    fn cards_order_test(a: Card, b: Card) {
+        assert!(a > b);
+    }
+
+    #[test]
+    fn hand_parser_test() {
+        let test_hand = hand("AAAAA  123").unwrap();
+        assert_eq!(test_hand.0, "");
+        assert_eq!(
+            test_hand.1,
+            Hand {
+                value: FiveOfAKind([A, A, A, A, A]),
+                bid: 123
+            }
+        );
+    }
+
+    #[test]
+    fn hand_value_parser_test() {
+        let test_hand = hand_value("AAAAA").unwrap();
+        assert_eq!(test_hand.0, "");
+        assert_eq!(test_hand.1, FiveOfAKind([A, A, A, A, A]),);
+    }
+
+    #[test]
+    fn six_card_hand_test() {
+        let err = Day7::parse("32T3KK 765").unwrap_err();
+        assert!(err.to_string().contains("InvalidHand(\"32T3KK\")"));
+    }
+}
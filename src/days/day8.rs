@@ -0,0 +1,159 @@
+use crate::{
+    dot::{Dot, Graph},
+    Answer, Solution,
+};
+use anyhow::{Context, Error, Result};
+use num::integer::lcm;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone)]
+struct NodeChildren {
+    left: String,
+    right: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    directions: String,
+    map: BTreeMap<String, NodeChildren>,
+}
+
+fn parse_map(input: &str) -> Result<BTreeMap<String, NodeChildren>> {
+    input.lines().try_fold(BTreeMap::new(), |mut map, line| {
+        let (node, children) = line
+            .split_once(" = ")
+            .context("Children should be well formed")?;
+        let (left, right) = children
+            .split_once(", ")
+            .context("Children should be well formed")?;
+        map.entry(node.to_string()).or_insert(NodeChildren {
+            left: left.replace('(', ""),
+            right: right.replace(')', ""),
+        });
+        Ok(map)
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (directions, map) = input
+            .split_once("\n\n")
+            .ok_or(Error::msg("Error parsing input"))?;
+
+        Ok(Network {
+            directions: directions.to_string(),
+            map: parse_map(map)?,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer> {
+        let map = &network.map;
+
+        let mut directions = network.directions.chars().cycle();
+        let mut steps = 0u32;
+        let mut current = "AAA".to_string();
+
+        while current != "ZZZ" {
+            steps += 1;
+            let current_children = map.get(&current).expect("Should exist");
+            let next_direction = directions.next().expect("Should exist");
+            current = match next_direction {
+                'L' => current_children.left.clone(),
+                'R' => current_children.right.clone(),
+                _ => panic!("Wrong direction"),
+            };
+        }
+
+        Ok(steps.into())
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer> {
+        let map = &network.map;
+
+        let mut directions = network.directions.chars().cycle();
+
+        let currents = map
+            .clone()
+            .into_keys()
+            .filter(|key| key.ends_with('A'))
+            .collect::<Vec<String>>();
+
+        let steps = currents
+            .clone()
+            .into_iter()
+            .map(|current| {
+                let mut current = current;
+                let mut steps = 0;
+                while !current.ends_with('Z') {
+                    steps += 1;
+                    let current_children = map.get(&current).expect("Should exist");
+                    let next_direction = directions.next().expect("Should exist");
+                    current = match next_direction {
+                        'L' => current_children.left.clone(),
+                        'R' => current_children.right.clone(),
+                        _ => panic!("Wrong direction"),
+                    };
+                }
+                steps
+            })
+            .collect::<Vec<u64>>();
+
+        let steps = steps.iter().fold(1, |acc, steps| lcm(acc, *steps));
+
+        Ok(steps.into())
+    }
+}
+
+impl Dot for Day8 {
+    /// Every node with its left and right child, start nodes are boxes and end nodes
+    /// double circles.
+    fn graph(network: &Self::Input) -> Result<Graph> {
+        let mut graph = Graph::directed();
+        for (node, children) in &network.map {
+            if node.ends_with('A') {
+                graph.node(node, &[("shape", "box")]);
+            } else if node.ends_with('Z') {
+                graph.node(node, &[("shape", "doublecircle")]);
+            }
+            graph.edge(node, &children.left, &[("label", "L")]).edge(
+                node,
+                &children.right,
+                &[("label", "R")],
+            );
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn graph_test() {
+        let network = Day8::parse(&crate::load_input("data/8_2.sample").unwrap()).unwrap();
+
+        assert_eq!(
+            Day8::graph(&network).unwrap().to_dot(),
+            "\
+digraph {
+  \"AAA\" [shape=\"box\"]
+  \"ZZZ\" [shape=\"doublecircle\"]
+  \"AAA\" -> \"BBB\" [label=\"L\"]
+  \"AAA\" -> \"BBB\" [label=\"R\"]
+  \"BBB\" -> \"AAA\" [label=\"L\"]
+  \"BBB\" -> \"ZZZ\" [label=\"R\"]
+  \"ZZZ\" -> \"ZZZ\" [label=\"L\"]
+  \"ZZZ\" -> \"ZZZ\" [label=\"R\"]
+}
+"
+        );
+    }
+}
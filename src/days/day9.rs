@@ -0,0 +1,79 @@
+use crate::{Answer, Solution};
+use anyhow::Result;
+use itertools::Itertools;
+use std::vec::Vec;
+
+fn build_tree(history: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut values_tree = vec![history];
+
+    while !values_tree
+        .last()
+        .expect("Last should exist")
+        .iter()
+        .all(|value| *value == 0)
+    {
+        let new_values = values_tree
+            .iter()
+            .last()
+            .expect("Should have a last")
+            .iter()
+            .tuple_windows()
+            .map(|(left, right)| right - left)
+            .collect();
+
+        values_tree.push(new_values);
+    }
+
+    values_tree
+}
+
+fn calc_extrapolate_forwards(history: Vec<i32>) -> i32 {
+    let mut values_tree = build_tree(history);
+
+    values_tree.iter_mut().for_each(|values| values.reverse());
+    values_tree.reverse();
+
+    values_tree.into_iter().skip(1).fold(0, |value, history| {
+        value + history.first().expect("Should have first")
+    })
+}
+
+fn calc_extrapolate_backwards(history: Vec<i32>) -> i32 {
+    let mut values_tree = build_tree(history);
+
+    values_tree.reverse();
+
+    values_tree.into_iter().skip(1).fold(0, |value, values| {
+        values.first().expect("Should have first") - value
+    })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::parse_lines_of_num::<i32>(input, " ")?)
+    }
+
+    fn part1(histories: &Self::Input) -> Result<Answer> {
+        Ok(histories
+            .iter()
+            .cloned()
+            .map(calc_extrapolate_forwards)
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(histories: &Self::Input) -> Result<Answer> {
+        Ok(histories
+            .iter()
+            .cloned()
+            .map(calc_extrapolate_backwards)
+            .sum::<i32>()
+            .into())
+    }
+}
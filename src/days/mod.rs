@@ -0,0 +1,88 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::DynSolution;
+
+/// Every day that has a solution, in order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+];
+
+pub fn solution(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1, 1, "two1nine\n\n")]
+    #[test_case(2, 1, "Game 1: 3 purple")]
+    #[test_case(4, 1, "Card 1: 41 48 83")]
+    #[test_case(5, 1, "seeds: x")]
+    #[test_case(5, 2, "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2")]
+    #[test_case(6, 1, "Time: 7")]
+    #[test_case(7, 1, "32T3K")]
+    #[test_case(8, 1, "LR\n\nAAA (BBB, BBB)")]
+    #[test_case(12, 1, "???.### 1,x")]
+    #[test_case(13, 1, "#.#\n..")]
+    #[test_case(15, 1, "rn=")]
+    #[test_case(16, 2, "")]
+    #[test_case(18, 1, "R 6 (#70c714)")]
+    #[test_case(19, 1, "px{a<2006:qkq,rfg}\n\n{x=787,q=2}")]
+    #[test_case(20, 1, "broadcaster")]
+    #[test_case(22, 1, "1,0,1~1,2")]
+    #[test_case(23, 1, "#.###\n#.###\n#####")]
+    fn malformed_input_test(day: u8, part: u8, input: &str) {
+        assert!(solution(day).unwrap().solve_text(input, part).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! Writing graphs in the DOT language of Graphviz.
+
+use crate::{input::Input, Solution};
+use anyhow::{Context, Result};
+use std::{
+    fmt::{Display, Write},
+    path::{Path, PathBuf},
+};
+
+type Attributes = Vec<(String, String)>;
+
+/// A graph built up node by node and edge by edge, in the order they were added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    directed: bool,
+    nodes: Vec<(String, Attributes)>,
+    edges: Vec<(String, String, Attributes)>,
+}
+
+impl Graph {
+    pub fn directed() -> Self {
+        Self {
+            directed: true,
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::directed()
+        }
+    }
+
+    /// Adds a node with its attributes, like `[("shape", "box")]`.
+    ///
+    /// Nodes only need to be added to give them attributes, edges add their ends.
+    pub fn node(&mut self, id: impl Display, attributes: &[(&str, &str)]) -> &mut Self {
+        self.nodes.push((id.to_string(), to_owned(attributes)));
+        self
+    }
+
+    pub fn edge(
+        &mut self,
+        from: impl Display,
+        to: impl Display,
+        attributes: &[(&str, &str)],
+    ) -> &mut Self {
+        self.edges
+            .push((from.to_string(), to.to_string(), to_owned(attributes)));
+        self
+    }
+
+    pub fn to_dot(&self) -> String {
+        let (kind, arrow) = match self.directed {
+            true => ("digraph", "->"),
+            false => ("graph", "--"),
+        };
+
+        let mut dot = format!("{kind} {{\n");
+        for (id, attributes) in &self.nodes {
+            writeln!(dot, "  {}{}", quote(id), format_attributes(attributes)).unwrap();
+        }
+        for (from, to, attributes) in &self.edges {
+            writeln!(
+                dot,
+                "  {} {arrow} {}{}",
+                quote(from),
+                quote(to),
+                format_attributes(attributes)
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_dot())
+            .with_context(|| format!("Couldn't write the graph to {}", path.display()))
+    }
+}
+
+fn to_owned(attributes: &[(&str, &str)]) -> Attributes {
+    attributes
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn format_attributes(attributes: &Attributes) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+
+    let attributes = attributes
+        .iter()
+        .map(|(key, value)| format!("{key}={}", quote(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(" [{attributes}]")
+}
+
+/// A solution whose puzzle is a graph worth looking at.
+pub trait Dot: Solution {
+    fn graph(input: &Self::Input) -> Result<Graph>;
+
+    /// Reads and parses `input` and writes its graph to `path`.
+    fn export_dot(input: impl Into<Input>, path: &Path) -> Result<()> {
+        let input = Self::parse(&input.into().read()?)?;
+        Self::graph(&input)?.save(path)
+    }
+}
+
+/// Command line of the day binaries that can export their graph.
+#[derive(Debug, clap::Parser)]
+pub struct DotArgs {
+    /// Write the graph of the puzzle to the given file in the DOT format of Graphviz
+    /// instead of solving it
+    #[arg(long)]
+    pub dot: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn directed_test() {
+        let mut graph = Graph::directed();
+        graph
+            .node("a", &[("shape", "box")])
+            .edge("a", "b", &[])
+            .edge("b", "a", &[("label", "back"), ("color", "red")]);
+
+        assert_eq!(
+            graph.to_dot(),
+            "\
+digraph {
+  \"a\" [shape=\"box\"]
+  \"a\" -> \"b\"
+  \"b\" -> \"a\" [label=\"back\", color=\"red\"]
+}
+"
+        );
+    }
+
+    #[test]
+    fn undirected_test() {
+        let mut graph = Graph::undirected();
+        graph.edge(1, 2, &[("label", "3")]);
+
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n  \"1\" -- \"2\" [label=\"3\"]\n}\n"
+        );
+    }
+
+    #[test]
+    fn escape_test() {
+        let mut graph = Graph::directed();
+        graph.node("say \"hi\"", &[("label", "a\\b")]);
+
+        assert!(graph
+            .to_dot()
+            .contains("\"say \\\"hi\\\"\" [label=\"a\\\\b\"]"));
+    }
+
+    #[test]
+    fn save_test() {
+        let path = temp_dir("dot_save").join("graph.dot");
+        let mut graph = Graph::directed();
+        graph.edge("a", "b", &[]);
+
+        graph.save(&path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(path).unwrap(), graph.to_dot());
+    }
+}
@@ -0,0 +1,312 @@
+use anyhow::{Context, Result};
+use reqwest::{header, StatusCode};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// A single puzzle of an Advent of Code event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: u8) -> Self {
+        Puzzle { year, day }
+    }
+
+    pub fn input_url(&self, base_url: &str) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            base_url.trim_end_matches('/'),
+            self.year,
+            self.day
+        )
+    }
+
+    pub fn puzzle_url(&self, base_url: &str) -> String {
+        format!(
+            "{}/{}/day/{}",
+            base_url.trim_end_matches('/'),
+            self.year,
+            self.day
+        )
+    }
+
+    pub fn answer_url(&self, base_url: &str) -> String {
+        format!("{}/answer", self.puzzle_url(base_url))
+    }
+
+    /// Fills `{year}` and `{day}` in the given layout, e.g. `data/{year}/{day}.input`.
+    pub fn input_path(&self, layout: &str) -> PathBuf {
+        PathBuf::from(
+            layout
+                .replace("{year}", &self.year.to_string())
+                .replace("{day}", &self.day.to_string()),
+        )
+    }
+}
+
+/// Why adventofcode.com didn't hand out an input.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The session cookie is missing, wrong or has expired.
+    ExpiredSession,
+    /// The puzzle doesn't exist or isn't unlocked yet.
+    NotUnlocked,
+    /// Any other unexpected status code.
+    Status(StatusCode),
+    Network(reqwest::Error),
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DownloadError::ExpiredSession => {
+                write!(
+                    f,
+                    "The session cookie was rejected, log in again and update it"
+                )
+            }
+            DownloadError::NotUnlocked => write!(f, "The puzzle isn't unlocked yet"),
+            DownloadError::Status(status) => write!(f, "Unexpected response {status}"),
+            DownloadError::Network(err) => write!(f, "Network error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DownloadError::Network(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<StatusCode> for DownloadError {
+    fn from(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                DownloadError::ExpiredSession
+            }
+            StatusCode::NOT_FOUND => DownloadError::NotUnlocked,
+            status => DownloadError::Status(status),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Download {
+    Saved,
+    /// The input already existed and wasn't downloaded again.
+    Cached,
+}
+
+/// Downloads the input at `url` into `path`, creating missing directories.
+///
+/// An existing input is kept unless `force` is set, without asking for the session
+/// cookie. The input is written to a temporary file first, so a failed download never
+/// leaves a partial input behind.
+pub async fn fetch_input(
+    url: &str,
+    path: &Path,
+    cookie: impl FnOnce() -> Result<String>,
+    force: bool,
+) -> Result<Download> {
+    if path.exists() && !force {
+        return Ok(Download::Cached);
+    }
+
+    let content = fetch(url, Some(&cookie()?)).await?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Couldn't create {}", dir.display()))?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+
+    let saved = std::fs::write(&temp_path, content)
+        .with_context(|| format!("Couldn't write {}", temp_path.display()))
+        .and_then(|()| {
+            std::fs::rename(&temp_path, path)
+                .with_context(|| format!("Couldn't move the input to {}", path.display()))
+        });
+    if let Err(err) = saved {
+        // The temporary file may not exist, the original error is the one to report.
+        std::fs::remove_file(&temp_path).ok();
+        return Err(err);
+    }
+
+    Ok(Download::Saved)
+}
+
+/// Downloads the puzzle page at `url`, without a session cookie it only contains part 1.
+pub async fn fetch_puzzle(url: &str, cookie: Option<&str>) -> Result<String> {
+    let content = fetch(url, cookie).await?;
+    String::from_utf8(content).with_context(|| format!("The puzzle at {url} isn't valid UTF-8"))
+}
+
+async fn fetch(url: &str, cookie: Option<&str>) -> Result<Vec<u8>, DownloadError> {
+    let mut request = reqwest::Client::new().get(url);
+    if let Some(cookie) = cookie {
+        request = request.header(header::COOKIE, format!("session={cookie}"));
+    }
+    let res = request.send().await.map_err(DownloadError::Network)?;
+
+    if !res.status().is_success() {
+        return Err(res.status().into());
+    }
+    res.bytes()
+        .await
+        .map(|content| content.to_vec())
+        .map_err(DownloadError::Network)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{temp_dir, TestServer};
+    use test_case::test_case;
+
+    #[test_case(
+        "https://adventofcode.com",
+        "https://adventofcode.com/2023/day/5/input"
+    )]
+    #[test_case("http://localhost:8080/", "http://localhost:8080/2023/day/5/input")]
+    fn input_url_test(base_url: &str, expected: &str) {
+        assert_eq!(Puzzle::new(2023, 5).input_url(base_url), expected);
+    }
+
+    #[test_case("data/{day}.input", "data/7.input")]
+    #[test_case("data/{year}/{day}.input", "data/2022/7.input")]
+    fn input_path_test(layout: &str, expected: &str) {
+        assert_eq!(
+            Puzzle::new(2022, 7).input_path(layout),
+            PathBuf::from(expected)
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_puzzle_test() {
+        let server = TestServer::start(200, "<main>puzzle</main>").await;
+
+        let url = Puzzle::new(2023, 1).puzzle_url(&server.base_url);
+        let puzzle = fetch_puzzle(&url, None).await.unwrap();
+
+        assert_eq!(puzzle, "<main>puzzle</main>");
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2023/day/1 "));
+        assert!(!requests[0].contains("cookie"));
+    }
+
+    fn secret() -> Result<String> {
+        Ok("secret".to_string())
+    }
+
+    fn download_error(err: &anyhow::Error) -> &DownloadError {
+        err.downcast_ref().unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_input_test() {
+        let server = TestServer::start(200, "1 2 3\n").await;
+        let path = temp_dir("fetch_input").join("2023/1.input");
+
+        let url = format!("{}/2023/day/1/input", server.base_url);
+        let download = fetch_input(&url, &path, secret, false).await.unwrap();
+
+        assert_eq!(download, Download::Saved);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2023/day/1/input "));
+        assert!(requests[0].contains("cookie: session=secret"));
+    }
+
+    #[tokio::test]
+    async fn cached_input_test() {
+        let server = TestServer::start(200, "new").await;
+        let path = temp_dir("cached_input").join("1.input");
+        std::fs::write(&path, "old").unwrap();
+
+        let download = fetch_input(
+            &server.base_url,
+            &path,
+            || unreachable!("Cached inputs don't need the session"),
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(download, Download::Cached);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+        assert!(server.requests().is_empty());
+
+        let download = fetch_input(&server.base_url, &path, secret, true)
+            .await
+            .unwrap();
+        assert_eq!(download, Download::Saved);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[test_case(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+    )]
+    #[test_case(
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks!"
+    )]
+    #[test_case(500, "Internal Server Error")]
+    #[tokio::test]
+    async fn failed_download_test(status: u16, body: &str) {
+        let server = TestServer::start(status, body).await;
+        let dir = temp_dir(&format!("failed_download_{status}"));
+        let path = dir.join("1.input");
+        std::fs::write(&path, "old").unwrap();
+
+        let err = fetch_input(&server.base_url, &path, secret, true)
+            .await
+            .unwrap_err();
+
+        match (status, download_error(&err)) {
+            (400, DownloadError::ExpiredSession) | (404, DownloadError::NotUnlocked) => {}
+            (500, DownloadError::Status(StatusCode::INTERNAL_SERVER_ERROR)) => {}
+            (_, err) => panic!("Unexpected error {err:?}"),
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_move_test() {
+        let server = TestServer::start(200, "1 2 3\n").await;
+        let dir = temp_dir("failed_move");
+        // A directory in the way of the input can't be replaced by it.
+        let path = dir.join("1.input");
+        std::fs::create_dir_all(path.join("taken")).unwrap();
+
+        let err = fetch_input(&server.base_url, &path, secret, true)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().starts_with("Couldn't move the input to"));
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn network_error_test() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2023/day/1/input", listener.local_addr().unwrap());
+        drop(listener);
+        let path = temp_dir("network_error").join("1.input");
+
+        let err = fetch_input(&url, &path, secret, false).await.unwrap_err();
+
+        assert!(matches!(download_error(&err), DownloadError::Network(_)));
+        assert!(!path.exists());
+    }
+}
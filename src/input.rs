@@ -0,0 +1,185 @@
+use anyhow::{Context, Result};
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Where the text of a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A file, relative paths are read from the current directory.
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+    /// Compiled into the binary, see [`puzzle_input!`](crate::puzzle_input).
+    Embedded(&'static str),
+}
+
+impl Input {
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Input::Path(path.into())
+    }
+
+    pub fn text(text: impl Into<String>) -> Self {
+        Input::Text(text.into())
+    }
+
+    /// A file in the `data` directory of this crate, so checkouts work from anywhere.
+    pub fn data(name: &str) -> Self {
+        Input::Path(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("data")
+                .join(name),
+        )
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Input::Path(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Couldn't read {}", path.display())),
+            Input::Stdin => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .context("Couldn't read from stdin")?;
+                Ok(text)
+            }
+            Input::Text(text) => Ok(text.clone()),
+            Input::Embedded(text) => Ok(text.to_string()),
+        }
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Path(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "stdin"),
+            Input::Text(_) => write!(f, "the given text"),
+            Input::Embedded(_) => write!(f, "the embedded input"),
+        }
+    }
+}
+
+/// Strings are paths and `-` is stdin, use [`Input::text`] for the input itself.
+impl From<&str> for Input {
+    fn from(path: &str) -> Self {
+        match path {
+            "-" => Input::Stdin,
+            path => Input::path(path),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Input::from(s))
+    }
+}
+
+impl From<&Path> for Input {
+    fn from(path: &Path) -> Self {
+        Input::path(path)
+    }
+}
+
+impl From<PathBuf> for Input {
+    fn from(path: PathBuf) -> Self {
+        Input::Path(path)
+    }
+}
+
+impl From<&Input> for Input {
+    fn from(input: &Input) -> Self {
+        input.clone()
+    }
+}
+
+/// The inputs compiled into the binary by `build.rs`, the first is day 1.
+#[cfg(feature = "embed-inputs")]
+#[doc(hidden)]
+pub static EMBEDDED: [Option<&str>; 25] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// The embedded input of a day, or its file if it wasn't there when building.
+#[doc(hidden)]
+pub fn embedded_or_data(embedded: Option<&'static str>, day: u8) -> Input {
+    match embedded {
+        Some(text) => Input::Embedded(text),
+        None => Input::data(&format!("{day}.input")),
+    }
+}
+
+/// The real input of a day, `data/<day>.input` of this crate.
+///
+/// With the `embed-inputs` feature the file is compiled into the binary instead.
+/// Inputs that are missing when building are still read from the file when run.
+#[cfg(feature = "embed-inputs")]
+#[macro_export]
+macro_rules! puzzle_input {
+    ($day:literal) => {
+        $crate::input::embedded_or_data($crate::input::EMBEDDED[$day - 1], $day)
+    };
+}
+
+/// The real input of a day, `data/<day>.input` of this crate.
+///
+/// With the `embed-inputs` feature the file is compiled into the binary instead.
+/// Inputs that are missing when building are still read from the file when run.
+#[cfg(not(feature = "embed-inputs"))]
+#[macro_export]
+macro_rules! puzzle_input {
+    ($day:literal) => {
+        $crate::input::Input::data(concat!($day, ".input"))
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn read_test() {
+        let path = temp_dir("input_read").join("1.input");
+        std::fs::write(&path, "1abc2\n").unwrap();
+
+        assert_eq!(Input::path(&path).read().unwrap(), "1abc2\n");
+        assert_eq!(Input::text("pqr3stu8vwx").read().unwrap(), "pqr3stu8vwx");
+        assert_eq!(Input::Embedded("a1b2").read().unwrap(), "a1b2");
+        assert!(Input::path(path.with_extension("missing")).read().is_err());
+    }
+
+    #[test]
+    fn data_test() {
+        let Input::Path(path) = Input::data("2.sample") else {
+            panic!("Data inputs are files");
+        };
+        assert!(path.is_absolute());
+        assert_eq!(
+            Input::Path(path).read().unwrap(),
+            Input::path("data/2.sample").read().unwrap()
+        );
+    }
+
+    #[test]
+    fn embedded_or_data_test() {
+        assert_eq!(embedded_or_data(Some("a1b2"), 1), Input::Embedded("a1b2"));
+        assert_eq!(embedded_or_data(None, 24), Input::data("24.input"));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!("-".parse::<Input>().unwrap(), Input::Stdin);
+        assert_eq!(Input::from("-"), Input::Stdin);
+        assert_eq!(
+            "data/1.input".parse::<Input>().unwrap(),
+            Input::path("data/1.input")
+        );
+        assert_eq!(Input::from("data/1.input").to_string(), "data/1.input");
+    }
+}
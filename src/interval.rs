@@ -0,0 +1,319 @@
+use num::PrimInt;
+use std::ops::{Range, RangeInclusive};
+
+/// Set of integers stored as sorted, disjoint and non-touching half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges making up the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |len, range| len + (range.end - range.start))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // The first range ending after `value` is the only one that can contain it.
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Ranges touching the new one are merged into it.
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = match self.ranges[first..last] {
+            [] => range,
+            ref touching => {
+                range.start.min(touching[0].start)..range.end.max(touching[touching.len() - 1].end)
+            }
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = others.peek() {
+                if cut.end <= start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Splits into the values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Every value moved by `offset`.
+    pub fn offset(&self, offset: T) -> Self {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+
+    /// Moves the values inside each source range by its offset, values outside
+    /// of every source range stay where they are.
+    ///
+    /// The source ranges should not overlap, otherwise only the first one
+    /// containing a value moves it.
+    pub fn map(&self, mappings: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut unmapped = self.clone();
+        let mut mapped = Self::new();
+        for (source, offset) in mappings {
+            let source = Self::from(source);
+            mapped = mapped.union(&unmapped.intersection(&source).offset(offset));
+            unmapped = unmapped.difference(&source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from(*range.start()..*range.end() + T::one())
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+    use test_case::test_case;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test_case(&[0..5, 10..15], &[0..5, 10..15]; "disjoint")]
+    #[test_case(&[10..15, 0..5], &[0..5, 10..15]; "unsorted")]
+    #[test_case(&[0..5, 5..10], &[0..10]; "touching")]
+    #[test_case(&[0..5, 3..10, 12..14, 1..2], &[0..10, 12..14]; "overlapping")]
+    #[test_case(&[0..3, 5..7, 9..12, 2..10], &[0..12]; "bridging")]
+    #[test_case(&[3..3, 7..7], &[]; "empty")]
+    fn insert_test(ranges: &[Range<i64>], expected: &[Range<i64>]) {
+        assert_eq!(set(ranges).ranges(), expected);
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25]);
+
+        assert_eq!(a.union(&b), set(&[0..30]));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), set(&[10..20]));
+        assert_eq!(a.len(), 20);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(29)));
+        assert!(a.contains(9) && !a.contains(10) && a.contains(20));
+    }
+
+    #[test]
+    fn split_at_test() {
+        let (below, above) = set(&[0..10, 20..30]).split_at(25);
+        assert_eq!(below, set(&[0..10, 20..25]));
+        assert_eq!(above, set(&[25..30]));
+
+        let (below, above) = IntervalSet::from(1u32..=4000).split_at(1351);
+        assert_eq!((below.len(), above.len()), (1350, 2650));
+    }
+
+    #[test]
+    fn map_test() {
+        // The seed-to-soil map of day 5.
+        let soil = set(&[79..93, 55..68]).map([(98..100, -48), (50..98, 2)]);
+        assert_eq!(soil, set(&[57..70, 81..95]));
+
+        let moved = set(&[0..10]).map([(5..8, 100), (0..2, -10)]);
+        assert_eq!(moved, set(&[-10..-8, 2..5, 8..10, 105..108]));
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<i64>>> {
+        prop::collection::vec(
+            (-50i64..50, 0i64..20).prop_map(|(start, len)| start..start + len),
+            0..6,
+        )
+    }
+
+    fn values(ranges: &[Range<i64>]) -> BTreeSet<i64> {
+        ranges.iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn is_normalized(set: &IntervalSet<i64>) -> bool {
+        set.ranges().iter().all(|range| !range.is_empty())
+            && set
+                .ranges()
+                .windows(2)
+                .all(|pair| pair[0].end < pair[1].start)
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_sets(a in ranges(), b in ranges()) {
+            let (set_a, set_b) = (set(&a), set(&b));
+            let (values_a, values_b) = (values(&a), values(&b));
+
+            for (result, expected) in [
+                (set_a.union(&set_b), &values_a | &values_b),
+                (set_a.intersection(&set_b), &values_a & &values_b),
+                (set_a.difference(&set_b), &values_a - &values_b),
+            ] {
+                prop_assert!(is_normalized(&result));
+                prop_assert_eq!(values(result.ranges()), expected.clone());
+                prop_assert_eq!(result.len(), expected.len() as i64);
+            }
+        }
+
+        #[test]
+        fn split_at_partitions(a in ranges(), at in -60i64..80) {
+            let set = set(&a);
+            let (below, above) = set.split_at(at);
+
+            prop_assert!(is_normalized(&below) && is_normalized(&above));
+            prop_assert!(values(below.ranges()).iter().all(|&value| value < at));
+            prop_assert!(values(above.ranges()).iter().all(|&value| value >= at));
+            prop_assert_eq!(below.union(&above), set);
+        }
+
+        #[test]
+        fn map_moves_every_value_once(a in ranges(), offset in -30i64..30) {
+            let set = set(&a);
+            let mapped = set.map([(-20..0, offset), (10..30, -offset)]);
+
+            let expected: BTreeSet<i64> = values(&a)
+                .into_iter()
+                .map(|value| match value {
+                    -20..=-1 => value + offset,
+                    10..=29 => value - offset,
+                    _ => value,
+                })
+                .collect();
+            prop_assert!(is_normalized(&mapped));
+            prop_assert_eq!(values(mapped.ranges()), expected);
+        }
+    }
+}
@@ -1,52 +1,427 @@
-use anyhow::Result;
-use num::Integer;
+use anyhow::{bail, Context, Result};
+use num::{traits::CheckedNeg, Integer, PrimInt, Signed, Zero};
+use serde::Deserialize;
 use std::{
     self,
-    fmt::Display,
-    ops::{Add, Sub},
+    any::Any,
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    path::PathBuf,
     str::FromStr,
 };
 
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod cycle;
+pub mod days;
+pub mod dot;
+pub mod download;
+pub mod input;
+pub mod interval;
+pub mod parallel;
+pub mod polygon;
+pub mod render;
+pub mod samples;
+pub mod scaffold;
+pub mod search;
+pub mod submit;
+#[cfg(test)]
+mod test_util;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Float(f64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Float(float) => write!(f, "{float}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<f64> for Answer {
+    fn from(value: f64) -> Self {
+        Answer::Float(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// The solution of a single day, split into parsing and solving both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    /// Reads and parses `input` and solves the given part with it.
+    ///
+    /// Plain strings are paths, see [`Input`](input::Input) for the other sources.
+    fn solve(input: impl Into<input::Input>, part: u8) -> Result<Answer> {
+        Self::solve_text(&input.into().read()?, part)
+    }
+
+    /// Parses the puzzle input itself and solves the given part with it.
+    fn solve_text(text: &str, part: u8) -> Result<Answer> {
+        Self::solve_part(&Self::parse(text)?, part)
+    }
+
+    fn solve_part(input: &Self::Input, part: u8) -> Result<Answer> {
+        match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => bail!("There is no part {part}"),
+        }
+    }
+
+    /// Solves a part with some constants of the puzzle replaced, for samples that
+    /// use other values than the real puzzle, like fewer steps.
+    ///
+    /// Days without such constants don't take any parameters.
+    fn solve_with(input: &Self::Input, part: u8, params: &Params) -> Result<Answer> {
+        params.ensure_known(&[])?;
+        Self::solve_part(input, part)
+    }
+}
+
+/// Constants of a puzzle by name, replacing the values of the real puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn new<'a>(params: impl IntoIterator<Item = (&'a str, i64)>) -> Self {
+        Self(
+            params
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The value of `name`, or `default` if it isn't replaced.
+    pub fn get(&self, name: &str, default: i64) -> i64 {
+        self.0.get(name).copied().unwrap_or(default)
+    }
+
+    /// Fails for any parameter not in `known`, which would silently be ignored.
+    pub fn ensure_known(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|name| !known.contains(&name.as_str())) {
+            Some(name) => bail!("There is no parameter {name}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .0
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", params.join(", "))
+    }
+}
+
+/// Object safe version of [`Solution`], used to keep every day in [`days::SOLUTIONS`].
+///
+/// The parsed input is passed around as [`Any`] and only ever handed back to
+/// the solution that parsed it.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer>;
+
+    fn part2(&self, input: &dyn Any) -> Result<Answer>;
+
+    fn solve(&self, input: &input::Input, part: u8) -> Result<Answer> {
+        self.solve_text(&input.read()?, part)
+    }
+
+    fn solve_text(&self, text: &str, part: u8) -> Result<Answer> {
+        let input = self.parse(text)?;
+
+        match part {
+            1 => self.part1(input.as_ref()),
+            2 => self.part2(input.as_ref()),
+            _ => bail!("There is no part {part}"),
+        }
+    }
+
+    /// See [`Solution::solve_with`].
+    fn solve_with(&self, input: &input::Input, part: u8, params: &Params) -> Result<Answer>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(<S as Solution>::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer> {
+        <S as Solution>::part1(downcast_input::<S>(input)?)
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<Answer> {
+        <S as Solution>::part2(downcast_input::<S>(input)?)
+    }
+
+    fn solve_with(&self, input: &input::Input, part: u8, params: &Params) -> Result<Answer> {
+        let input = <S as Solution>::parse(&input.read()?)?;
+        <S as Solution>::solve_with(&input, part, params)
+    }
+}
+
+fn downcast_input<S>(input: &dyn Any) -> Result<&S::Input>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .with_context(|| format!("Input wasn't parsed by day {}", S::DAY))
+}
+
+/// A line of the input that couldn't be parsed.
+#[derive(Debug)]
+pub struct ParseLineError<E> {
+    /// `None` when parsing a string that wasn't read from a file.
+    pub file: Option<PathBuf>,
+    /// Starting at 1, like in editors.
+    pub line: usize,
+    pub text: String,
+    pub error: E,
+}
+
+impl<E: Display> Display for ParseLineError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}", file.display(), self.line)?,
+            None => write!(f, "Line {}", self.line)?,
+        }
+        write!(f, ": Couldn't parse {:?}: {}", self.text, self.error)
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for ParseLineError<E> {}
+
+impl<E> ParseLineError<E> {
+    fn in_file(self, path: &str) -> Self {
+        Self {
+            file: Some(PathBuf::from(path)),
+            ..self
+        }
+    }
+}
+
+/// Parses every line of the file, failing on the first line that doesn't parse.
 pub fn read_one_per_line<T>(path: &str) -> Result<Vec<T>>
 where
     T: FromStr,
+    T::Err: Debug + Display + Send + Sync + 'static,
 {
-    Ok(std::fs::read_to_string(path)?
+    Ok(parse_one_per_line(&load_input(path)?).map_err(|err| err.in_file(path))?)
+}
+
+/// Parses every line, failing on the first line that doesn't parse.
+pub fn parse_one_per_line<T>(input: &str) -> std::result::Result<Vec<T>, ParseLineError<T::Err>>
+where
+    T: FromStr,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse::<T>().map_err(|error| ParseLineError {
+                file: None,
+                line: i + 1,
+                text: line.to_string(),
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Like [`read_one_per_line`], but lines that don't parse are skipped.
+pub fn read_one_per_line_lenient<T>(path: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+{
+    Ok(parse_one_per_line_lenient(&load_input(path)?))
+}
+
+/// Like [`parse_one_per_line`], but lines that don't parse are skipped.
+pub fn parse_one_per_line_lenient<T>(input: &str) -> Vec<T>
+where
+    T: FromStr,
+{
+    input
         .lines()
         .filter_map(|line| line.parse::<T>().ok())
-        .collect())
+        .collect()
 }
 
 pub fn load_input(path: &str) -> Result<String> {
     Ok(std::fs::read_to_string(path)?)
 }
 
+/// Parses every line as numbers separated by `delim`, failing on the first line with
+/// a value that doesn't parse.
 pub fn read_lines_of_num<T>(path: &str, delim: &str) -> Result<Vec<Vec<T>>>
 where
     T: Integer + FromStr,
+    T::Err: Debug + Display + Send + Sync + 'static,
 {
-    Ok(std::fs::read_to_string(path)?
+    Ok(parse_lines_of_num(&load_input(path)?, delim).map_err(|err| err.in_file(path))?)
+}
+
+/// Parses every line as numbers separated by `delim`, failing on the first line with
+/// a value that doesn't parse.
+pub fn parse_lines_of_num<T>(
+    input: &str,
+    delim: &str,
+) -> std::result::Result<Vec<Vec<T>>, ParseLineError<T::Err>>
+where
+    T: Integer + FromStr,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split(delim)
+                .map(|value| value.parse::<T>())
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|error| ParseLineError {
+                    file: None,
+                    line: i + 1,
+                    text: line.to_string(),
+                    error,
+                })
+        })
+        .collect()
+}
+
+/// Like [`read_lines_of_num`], but values that don't parse are skipped.
+pub fn read_lines_of_num_lenient<T>(path: &str, delim: &str) -> Result<Vec<Vec<T>>>
+where
+    T: Integer + FromStr,
+{
+    Ok(parse_lines_of_num_lenient(&load_input(path)?, delim))
+}
+
+/// Like [`parse_lines_of_num`], but values that don't parse are skipped.
+pub fn parse_lines_of_num_lenient<T>(input: &str, delim: &str) -> Vec<Vec<T>>
+where
+    T: Integer + FromStr,
+{
+    input
         .lines()
         .map(|line| {
             line.split(delim)
                 .filter_map(|value| value.parse::<T>().ok())
                 .collect()
         })
-        .collect())
+        .collect()
 }
 
+/// Integer vector, `Vec2D` on its own is `Vec2D<i32>`.
+///
+/// On grids `y` grows downwards, so `UP` is towards negative `y`.
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct Vec2D {
-    pub x: i32,
-    pub y: i32,
+pub struct Vec2D<T = i32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Display for Vec2D {
+impl<T: Display> Display for Vec2D<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{},{}", self.x, self.y)
     }
 }
 
+/// Parses `x,y`, the format of `Display`.
+impl<T: FromStr> FromStr for Vec2D<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s
+            .split_once(',')
+            .with_context(|| format!("{s:?} is not a vector like \"x,y\""))?;
+
+        Ok(Self {
+            x: x.trim()
+                .parse()
+                .with_context(|| format!("Invalid x in {s:?}"))?,
+            y: y.trim()
+                .parse()
+                .with_context(|| format!("Invalid y in {s:?}"))?,
+        })
+    }
+}
+
+impl<T> Vec2D<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Other coordinate types get these from `Zero` and `Direction`.
 impl Vec2D {
     pub const ZERO: Self = Self { x: 0, y: 0 };
 
@@ -54,30 +429,982 @@ impl Vec2D {
     pub const RIGHT: Self = Self { x: 1, y: 0 };
     pub const DOWN: Self = Self { x: 0, y: 1 };
     pub const LEFT: Self = Self { x: -1, y: 0 };
+}
 
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
+impl<T: Zero> Zero for Vec2D<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+impl<T: PrimInt + Signed + CheckedNeg> Vec2D<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = self - other;
+        distance.x.abs() + distance.y.abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        let distance = self - other;
+        distance.x.abs().max(distance.y.abs())
+    }
+
+    /// Rotates by 90° counterclockwise, as seen with `y` growing downwards.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Rotates by 90° clockwise, as seen with `y` growing downwards.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Orthogonal neighbours, clockwise starting with the one above.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction.into())
+    }
+
+    /// Orthogonal and diagonal neighbours, row by row.
+    pub fn neighbors8(self) -> [Self; 8] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(-one, -one),
+            Self::new(zero, -one),
+            Self::new(one, -one),
+            Self::new(-one, zero),
+            Self::new(one, zero),
+            Self::new(-one, one),
+            Self::new(zero, one),
+            Self::new(one, one),
+        ]
+        .map(|offset| self + offset)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(&rhs.x)?,
+            self.y.checked_add(&rhs.y)?,
+        ))
+    }
+
+    /// `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(&rhs.x)?,
+            self.y.checked_sub(&rhs.y)?,
+        ))
+    }
+
+    /// `None` on overflow.
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(&rhs)?,
+            self.y.checked_mul(&rhs)?,
+        ))
+    }
+
+    /// `None` on overflow.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.x.checked_neg()?, self.y.checked_neg()?))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2D<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2D<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2D<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2D<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2D<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// Integer vector in 3D, `Vec3D` on its own is `Vec3D<i32>`.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Vec3D<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Display> Display for Vec3D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Parses `x,y,z`, the format of `Display`.
+impl<T: FromStr> FromStr for Vec3D<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let coordinates: Vec<&str> = s.split(',').collect();
+        let [x, y, z] = coordinates[..] else {
+            bail!("{s:?} is not a vector like \"x,y,z\"");
+        };
+
+        Ok(Self {
+            x: x.trim()
+                .parse()
+                .with_context(|| format!("Invalid x in {s:?}"))?,
+            y: y.trim()
+                .parse()
+                .with_context(|| format!("Invalid y in {s:?}"))?,
+            z: z.trim()
+                .parse()
+                .with_context(|| format!("Invalid z in {s:?}"))?,
+        })
+    }
+}
+
+impl<T> Vec3D<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Projection onto the XY plane.
+    pub fn xy(self) -> Vec2D<T> {
+        Vec2D::new(self.x, self.y)
+    }
+}
+
+impl<T: PrimInt + Signed> Vec3D<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = self - other;
+        distance.x.abs() + distance.y.abs() + distance.z.abs()
+    }
+}
+
+impl<T: Zero> Zero for Vec3D<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
     }
 }
 
-impl Add for Vec2D {
-    type Output = Vec2D;
+impl<T: Add<Output = T>> Add for Vec3D<T> {
+    type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
+            z: self.z + rhs.z,
         }
     }
 }
 
-impl Sub for Vec2D {
-    type Output = Vec2D;
+impl<T: Sub<Output = T>> Sub for Vec3D<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3D<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec3D<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec3D<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Axis-aligned box of cells, both corners are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T = i32> {
+    pub min: Vec3D<T>,
+    pub max: Vec3D<T>,
+}
+
+impl<T: PrimInt> Cuboid<T> {
+    /// Spans the cells between two opposite corners, in any order.
+    pub fn new(a: Vec3D<T>, b: Vec3D<T>) -> Self {
+        Self {
+            min: Vec3D::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Vec3D::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// Number of cells along each axis.
+    pub fn size(&self) -> Vec3D<T> {
+        self.max - self.min + Vec3D::new(T::one(), T::one(), T::one())
+    }
+
+    pub fn volume(&self) -> T {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, cell: Vec3D<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&cell.x)
+            && (self.min.y..=self.max.y).contains(&cell.y)
+            && (self.min.z..=self.max.z).contains(&cell.z)
+    }
+
+    /// The cells inside both cuboids, `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Vec3D::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Vec3D::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// Whether the cuboids overlap when seen from above, ignoring `z`.
+    pub fn overlaps_xy(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    pub fn translate(&self, offset: Vec3D<T>) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
         }
     }
+
+    /// Every cell, with `x` changing fastest and `z` slowest.
+    pub fn cells(&self) -> impl Iterator<Item = Vec3D<T>> {
+        let Self { min, max } = *self;
+        num::range_inclusive(min.z, max.z).flat_map(move |z| {
+            num::range_inclusive(min.y, max.y).flat_map(move |y| {
+                num::range_inclusive(min.x, max.x).map(move |x| Vec3D::new(x, y, z))
+            })
+        })
+    }
+}
+
+/// Parses `x,y,z~x,y,z`, two opposite corners.
+impl<T: PrimInt + FromStr> FromStr for Cuboid<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (a, b) = s
+            .split_once('~')
+            .with_context(|| format!("{s:?} is not a cuboid like \"x,y,z~x,y,z\""))?;
+
+        Ok(Self::new(a.parse()?, b.parse()?))
+    }
+}
+
+/// One of the four directions on a grid, `Up` is towards negative `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> From<Direction> for Vec2D<T> {
+    fn from(direction: Direction) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        match direction {
+            Direction::Up => Vec2D::new(zero, -one),
+            Direction::Right => Vec2D::new(one, zero),
+            Direction::Down => Vec2D::new(zero, one),
+            Direction::Left => Vec2D::new(-one, zero),
+        }
+    }
+}
+
+impl<T: PrimInt + Signed + Display> TryFrom<Vec2D<T>> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(offset: Vec2D<T>) -> Result<Self> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| Vec2D::from(direction) == offset)
+            .with_context(|| format!("{offset} is not a direction"))
+    }
+}
+
+/// Accepts `U/R/D/L`, `^>v<` and `N/E/S/W`.
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            c => bail!("{c:?} is not a direction"),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => bail!("{s:?} is not a direction"),
+        }
+    }
+}
+
+/// Dense 2D grid stored row by row, indexed by `Vec2D` with `x` as column and `y` as row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "A {width}x{height} grid needs {} cells, got {}",
+                width * height,
+                cells.len()
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a character map, every line is a row and all rows must have the same width.
+    ///
+    /// Errors name the line and column (both starting at 1) of the offending character.
+    pub fn parse_with<F>(input: &str, mut parse: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell =
+                    parse(c).with_context(|| format!("Line {}, column {}: {c:?}", y + 1, x + 1))?;
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => bail!(
+                    "Line {} is {line_width} wide, expected {width} like the first line",
+                    y + 1
+                ),
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Grid::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Vec2D) -> bool {
+        self.cell_index(position).is_some()
+    }
+
+    fn cell_index(&self, position: Vec2D) -> Option<usize> {
+        let x = usize::try_from(position.x).ok()?;
+        let y = usize::try_from(position.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn position(&self, index: usize) -> Vec2D {
+        Vec2D::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, position: Vec2D) -> Option<&T> {
+        self.cell_index(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Vec2D) -> Option<&mut T> {
+        self.cell_index(position)
+            .map(|index| &mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let len = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(len)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2D> + '_ {
+        (0..self.cells.len()).map(|index| self.position(index))
+    }
+
+    /// Every cell with its position in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2D, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position(index), cell))
+    }
+
+    /// Position of the first cell matching `predicate` in row-major order.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vec2D> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position(index))
+    }
+
+    /// Orthogonal neighbours of `position` that are inside the grid.
+    pub fn neighbors4(&self, position: Vec2D) -> impl Iterator<Item = Vec2D> + '_ {
+        position
+            .neighbors4()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Orthogonal and diagonal neighbours of `position` that are inside the grid.
+    pub fn neighbors8(&self, position: Vec2D) -> impl Iterator<Item = Vec2D> + '_ {
+        position
+            .neighbors8()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+}
+
+impl<T> Index<Vec2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec2D) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Vec2D> for Grid<T> {
+    fn index_mut(&mut self, position: Vec2D) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+    use std::{num::ParseIntError, path::Path};
+    use test_case::test_case;
+
+    #[test]
+    fn params_test() {
+        let params = Params::new([("steps", 6), ("expansion", 10)]);
+
+        assert_eq!(params.get("steps", 64), 6);
+        assert_eq!(params.get("cycles", 3), 3);
+        assert_eq!(params.to_string(), "expansion=10, steps=6");
+        assert!(params.ensure_known(&["expansion", "steps"]).is_ok());
+        assert!(params.ensure_known(&["steps"]).is_err());
+        assert!(Params::default().ensure_known(&[]).is_ok());
+    }
+
+    #[test]
+    fn parse_one_per_line_test() {
+        assert_eq!(
+            parse_one_per_line::<u32>("1\n22\n333\n").unwrap(),
+            vec![1, 22, 333]
+        );
+
+        let err = parse_one_per_line::<u32>("1\n2x\n3\n").unwrap_err();
+        assert_eq!((err.file, err.line, err.text.as_str()), (None, 2, "2x"));
+        assert_eq!(err.error, "2x".parse::<u32>().unwrap_err());
+
+        assert_eq!(parse_one_per_line_lenient::<u32>("1\n2x\n3\n"), vec![1, 3]);
+    }
+
+    #[test]
+    fn parse_lines_of_num_test() {
+        assert_eq!(
+            parse_lines_of_num::<i32>("1 -2\n3\n", " ").unwrap(),
+            vec![vec![1, -2], vec![3]]
+        );
+
+        let err = parse_lines_of_num::<i32>("1 2\n3  4\n", " ").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "3  4"));
+
+        assert_eq!(
+            parse_lines_of_num_lenient::<i32>("1 2\n3  4\n", " "),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn read_one_per_line_error_test() {
+        let path = test_util::temp_dir("read_one_per_line").join("input");
+        std::fs::write(&path, "4\nfour\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        let err = read_one_per_line::<u8>(path).unwrap_err();
+        let err = err.downcast_ref::<ParseLineError<ParseIntError>>().unwrap();
+        assert_eq!(err.file.as_deref(), Some(Path::new(path)));
+        assert_eq!(err.line, 2);
+        assert_eq!(
+            err.to_string(),
+            format!("{path}:2: Couldn't parse \"four\": invalid digit found in string")
+        );
+
+        assert_eq!(read_one_per_line_lenient::<u8>(path).unwrap(), vec![4]);
+    }
+
+    fn grid() -> Grid<u32> {
+        Grid::parse_with("123\n456\n", |c| c.to_digit(10).context("Not a digit")).unwrap()
+    }
+
+    #[test]
+    fn grid_parse_with_test() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Vec2D::new(2, 1)), Some(&6));
+        assert_eq!(grid[Vec2D::new(0, 1)], 4);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn grid_parse_error_test() {
+        let err =
+            Grid::parse_with("123\n4x6", |c| c.to_digit(10).context("Not a digit")).unwrap_err();
+        assert_eq!(err.to_string(), "Line 2, column 2: 'x'");
+
+        let err = Grid::parse_with("123\n45", Ok).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Line 2 is 2 wide, expected 3 like the first line"
+        );
+    }
+
+    #[test]
+    fn grid_get_test() {
+        let mut grid = grid();
+        assert_eq!(grid.get(Vec2D::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2D::new(0, 2)), None);
+        assert_eq!(grid.get(Vec2D::new(-1, 0)), None);
+
+        *grid.get_mut(Vec2D::new(1, 0)).unwrap() = 9;
+        grid[Vec2D::new(2, 0)] = 8;
+        assert_eq!(grid.row(0), Some(&[1, 9, 8][..]));
+        assert_eq!(grid.row(2), None);
+    }
+
+    #[test]
+    fn grid_rows_and_columns_test() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect_vec(),
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]]
+        );
+        assert_eq!(grid.column(1).collect_vec(), vec![&2, &5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect_vec())
+                .collect_vec(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+
+    #[test]
+    fn grid_iter_test() {
+        let grid = grid();
+        assert_eq!(grid.iter().nth(4), Some((Vec2D::new(1, 1), &5)),);
+        assert_eq!(grid.positions().last(), Some(Vec2D::new(2, 1)));
+        assert_eq!(grid.find(|&cell| cell > 4), Some(Vec2D::new(1, 1)));
+    }
+
+    #[test]
+    fn direction_turn_test() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn vec2d_ops_test() {
+        let mut v = Vec2D::new(3, -2);
+        assert_eq!(v * 2, Vec2D::new(6, -4));
+        assert_eq!(-v, Vec2D::new(-3, 2));
+        v += Vec2D::RIGHT;
+        assert_eq!(v, Vec2D::new(4, -2));
+        v -= Vec2D::UP;
+        assert_eq!(v, Vec2D::new(4, -1));
+        assert_eq!(
+            Vec2D::<i64>::zero() + Vec2D::from(Direction::Down) * 5,
+            Vec2D::new(0, 5)
+        );
+    }
+
+    #[test_case(Vec2D::new(0, 0), Vec2D::new(3, -4), 7, 4)]
+    #[test_case(Vec2D::new(-1, 2), Vec2D::new(-1, 2), 0, 0)]
+    #[test_case(Vec2D::new(5, 5), Vec2D::new(-5, 4), 11, 10)]
+    fn vec2d_distance_test(a: Vec2D, b: Vec2D, manhattan: i32, chebyshev: i32) {
+        assert_eq!(a.manhattan(b), manhattan);
+        assert_eq!(b.manhattan(a), manhattan);
+        assert_eq!(a.chebyshev(b), chebyshev);
+    }
+
+    #[test]
+    fn vec2d_rotate_test() {
+        for direction in Direction::ALL {
+            let offset = Vec2D::<i64>::from(direction);
+            assert_eq!(offset.rotate_right(), direction.turn_right().into());
+            assert_eq!(offset.rotate_left(), direction.turn_left().into());
+        }
+        let v = Vec2D::new(2, 1);
+        assert_eq!(v.rotate_left().rotate_right(), v);
+        assert_eq!(v.rotate_right().rotate_right(), -v);
+    }
+
+    #[test]
+    fn vec2d_neighbors_test() {
+        let v = Vec2D::new(1, 1);
+        assert_eq!(
+            v.neighbors4(),
+            [
+                Vec2D::new(1, 0),
+                Vec2D::new(2, 1),
+                Vec2D::new(1, 2),
+                Vec2D::new(0, 1)
+            ]
+        );
+        let neighbors8 = v.neighbors8();
+        assert!(neighbors8
+            .iter()
+            .all(|&neighbor| v.chebyshev(neighbor) == 1));
+        assert_eq!(neighbors8.iter().unique().count(), 8);
+    }
+
+    #[test_case("3,-4", Vec2D::new(3, -4))]
+    #[test_case("0, 12", Vec2D::new(0, 12))]
+    fn vec2d_from_str_test(input: &str, expected: Vec2D) {
+        let v: Vec2D = input.parse().unwrap();
+        assert_eq!(v, expected);
+        assert_eq!(v.to_string().parse::<Vec2D>().unwrap(), v);
+    }
+
+    #[test_case("3")]
+    #[test_case("3,a")]
+    #[test_case("3,4,5")]
+    fn vec2d_from_str_error_test(input: &str) {
+        assert!(input.parse::<Vec2D>().is_err());
+    }
+
+    #[test]
+    fn vec2d_i64_round_trip_test() {
+        let v = Vec2D::<i64>::new(i64::MIN, 10_000_000_000);
+        assert_eq!(v.to_string().parse::<Vec2D<i64>>().unwrap(), v);
+    }
+
+    #[test]
+    fn vec2d_checked_test() {
+        let max = Vec2D::new(i32::MAX, 0);
+        assert_eq!(max.checked_add(Vec2D::RIGHT), None);
+        assert_eq!(
+            max.checked_add(Vec2D::LEFT),
+            Some(Vec2D::new(i32::MAX - 1, 0))
+        );
+        assert_eq!(Vec2D::new(i32::MIN, 0).checked_sub(Vec2D::RIGHT), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Vec2D::new(2, 3).checked_mul(-2), Some(Vec2D::new(-4, -6)));
+        assert_eq!(Vec2D::new(0, i32::MIN).checked_neg(), None);
+    }
+
+    #[test]
+    fn vec3d_test() {
+        let v: Vec3D = "1,-2,3".parse().unwrap();
+        assert_eq!(v, Vec3D::new(1, -2, 3));
+        assert_eq!(v.to_string(), "1,-2,3");
+        assert!("1,2".parse::<Vec3D>().is_err());
+        assert!("1,2,3,4".parse::<Vec3D>().is_err());
+
+        assert_eq!(v + Vec3D::new(1, 1, 1), Vec3D::new(2, -1, 4));
+        assert_eq!(v - v, Vec3D::zero());
+        assert_eq!(-v * 2, Vec3D::new(-2, 4, -6));
+        assert_eq!(v.manhattan(Vec3D::zero()), 6);
+        assert_eq!(v.xy(), Vec2D::new(1, -2));
+    }
+
+    #[test]
+    fn cuboid_test() {
+        let cuboid: Cuboid = "2,0,5~0,1,5".parse().unwrap();
+        assert_eq!(cuboid.min, Vec3D::new(0, 0, 5));
+        assert_eq!(cuboid.max, Vec3D::new(2, 1, 5));
+        assert_eq!(cuboid.size(), Vec3D::new(3, 2, 1));
+        assert_eq!(cuboid.volume(), 6);
+        assert!(cuboid.contains(Vec3D::new(1, 1, 5)));
+        assert!(!cuboid.contains(Vec3D::new(1, 1, 4)));
+
+        let cells = cuboid.cells().collect_vec();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[1], Vec3D::new(1, 0, 5));
+        assert!(cells.iter().all(|&cell| cuboid.contains(cell)));
+
+        let moved = cuboid.translate(Vec3D::new(0, 0, -2));
+        assert_eq!(moved.min, Vec3D::new(0, 0, 3));
+        assert_eq!(moved.volume(), cuboid.volume());
+    }
+
+    #[test]
+    fn cuboid_intersection_test() {
+        let a = Cuboid::new(Vec3D::new(0, 0, 0), Vec3D::new(3, 3, 3));
+        let b = Cuboid::new(Vec3D::new(2, 1, 3), Vec3D::new(5, 5, 5));
+        let c = Cuboid::new(Vec3D::new(4, 0, 0), Vec3D::new(4, 0, 9));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new(Vec3D::new(2, 1, 3), Vec3D::new(3, 3, 3)))
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.intersection(&a), Some(a));
+
+        let above = a.translate(Vec3D::new(1, 1, 10));
+        assert!(a.overlaps_xy(&above));
+        assert_eq!(a.intersection(&above), None);
+        assert!(!a.overlaps_xy(&c));
+    }
+
+    #[test]
+    fn cuboid_i64_test() {
+        let cuboid = Cuboid::<i64>::new(Vec3D::zero(), Vec3D::new(99_999, 99_999, 99_999));
+        assert_eq!(cuboid.volume(), 1_000_000_000_000_000);
+    }
+
+    #[test]
+    fn direction_vec2d_test() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                Direction::try_from(Vec2D::<i32>::from(direction)).unwrap(),
+                direction
+            );
+        }
+        assert_eq!(Vec2D::from(Direction::Up), Vec2D::new(0, -1));
+        assert!(Direction::try_from(Vec2D::new(1, 1)).is_err());
+    }
+
+    #[test_case("URDL")]
+    #[test_case("^>v<")]
+    #[test_case("NESW")]
+    fn direction_parse_test(encoding: &str) {
+        let directions: Vec<Direction> = encoding
+            .chars()
+            .map(|c| c.to_string().parse().unwrap())
+            .collect();
+        assert_eq!(directions, Direction::ALL);
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UR".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn grid_neighbors_test() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4(Vec2D::new(0, 0)).collect_vec(),
+            vec![Vec2D::new(1, 0), Vec2D::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors4(Vec2D::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8(Vec2D::new(1, 1)).count(), 5);
+        assert_eq!(grid.neighbors8(Vec2D::new(1, 0)).count(), 5);
+    }
 }
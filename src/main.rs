@@ -1,14 +1,19 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use aoc23::{
+    answers::{load_answers, Outcome, ANSWERS_PATH},
+    bench,
+    config::{self, Config, DEFAULT_YEAR},
+    download::{fetch_input, fetch_puzzle, Download, Puzzle},
+    input::Input,
+    samples::{extract_samples, save_samples, SAMPLES_DIR},
+    submit::{submit_answer, Ledger, Submission, LEDGER_PATH},
+};
 use clap::Parser;
-use reqwest::header;
-use std::io::Cursor;
+use std::path::Path;
 
 extern crate dotenv;
 extern crate reqwest;
 
-#[macro_use]
-extern crate dotenv_codegen;
-
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -16,65 +21,424 @@ struct Args {
     /// Name of the person to greet
     #[clap(subcommand)]
     subcommand: Subcommand,
+    /// Session cookie used to talk to adventofcode.com, AOC_COOKIE takes precedence
+    #[arg(long, global = true)]
+    session: Option<String>,
 }
 
 #[derive(Parser, Debug)]
 enum Subcommand {
     /// Load the given days input
-    Load { day: u8 },
+    Load {
+        day: u8,
+        /// Event to load the input from
+        #[arg(long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        /// Where to save the input, `{year}` and `{day}` are replaced. Defaults to the
+        /// `input_path` in the config or `data/{day}.input` (`data/{year}/{day}.input` for
+        /// other years)
+        #[arg(long)]
+        output: Option<String>,
+        /// Download the input again even if it was already saved
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create and register a new day from a template
+    New { day: u8 },
+    /// Extract the example inputs from the puzzle page of the given day
+    Samples {
+        day: u8,
+        /// Event to load the puzzle from
+        #[arg(long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        /// Read the puzzle from a saved HTML page instead of downloading it
+        #[arg(long)]
+        page: Option<String>,
+        /// Samples to save as `data/<day>_<k>.sample`, by the numbers they are listed with.
+        /// Without it the samples are only listed
+        #[arg(long, value_delimiter = ',')]
+        pick: Vec<usize>,
+        /// Overwrite existing sample files
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run the solution of the given day
+    Run {
+        day: u8,
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file to use instead of `data/<day>.input`, `-` reads it from stdin
+        #[arg(long)]
+        input: Option<Input>,
+    },
+    /// Submit an answer and record it in `data/submissions.toml`
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(required_unless_present = "from_run")]
+        answer: Option<String>,
+        /// Submit the answer of the solution instead
+        #[arg(long, conflicts_with = "answer")]
+        from_run: bool,
+        /// Input file to use with --from-run instead of `data/<day>.input`, `-` reads
+        /// it from stdin
+        #[arg(long, requires = "from_run")]
+        input: Option<Input>,
+        /// Event to submit the answer to
+        #[arg(long, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+    },
+    /// Time loading, parsing and both parts of the given days, all solved days by default
+    Bench {
+        days: Vec<u8>,
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        #[arg(long, value_enum, default_value_t = BenchFormat::Table)]
+        format: BenchFormat,
+        /// Write the results to a file instead of printing them
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Check the solutions against the accepted answers in `data/answers.toml`
+    Verify {
+        /// Only verify the given day
+        day: Option<u8>,
+    },
     /// Show the usage of aoc
     Usage,
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum BenchFormat {
+    Table,
+    Json,
+    Markdown,
+}
+
 fn usage() {
     println!(
         "These are my solutions for aoc 2023.
-Each day is it's own binary so you can run it using:
+Each day can be run through aoc23 using:
+    cargo run --bin aoc23 run [1-25] [--part 1|2] [--input path|-]
+
+The solutions can be checked against the accepted answers using:
+    cargo run --bin aoc23 verify [1-25]
+
+Each day is also it's own binary so you can run it using:
     cargo run --bin day[1-25]
 
 Each day also has tests using the examples.
 These can be run using:
-    cargo test --bins
+    cargo test --lib
 
 Or a single day with:
-    cargo test --bin day[1-25]"
+    cargo test --lib day[1-25]_part"
     );
 }
 
-async fn fetch_url(url: String, file_name: String, cookie: String) -> Result<()> {
-    let client = reqwest::Client::new();
-    let res = client
-        .get(url)
-        .header(header::COOKIE, format!("session={cookie}"))
-        .send()
-        .await?;
-
-    let mut file = std::fs::File::create(file_name)?;
-    let mut content = Cursor::new(res.bytes().await?);
-    std::io::copy(&mut content, &mut file)?;
+fn run(day: u8, part: Option<u8>, input: Option<Input>) -> Result<()> {
+    let input = input.unwrap_or(Input::data(&format!("{day}.input")));
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let solution =
+        aoc23::days::solution(day).with_context(|| format!("Day {day} is not solved yet"))?;
+
+    // Read once, stdin can't be read again for the second part.
+    let text = input.read()?;
+    for part in parts {
+        let answer = solution
+            .solve_text(&text, part)
+            .with_context(|| format!("Failed to solve day {day} part {part} using {input}"))?;
+        println!("Day {day} Part {part}: {answer}");
+    }
+
+    Ok(())
+}
+
+fn bench(
+    days: Vec<u8>,
+    iterations: u32,
+    format: BenchFormat,
+    output: Option<String>,
+) -> Result<()> {
+    let solutions = if days.is_empty() {
+        aoc23::days::SOLUTIONS.to_vec()
+    } else {
+        days.iter()
+            .map(|&day| {
+                aoc23::days::solution(day).with_context(|| format!("Day {day} is not solved yet"))
+            })
+            .collect::<Result<_>>()?
+    };
+
+    let mut benches = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        let input = Input::data(&format!("{day}.input"));
+        match bench::bench_day(solution, &input, iterations as usize) {
+            Ok(bench) => benches.push(bench),
+            Err(err) if days.is_empty() => eprintln!("Skipping day {day}: {err:#}"),
+            Err(err) => return Err(err.context(format!("Failed to bench day {day}"))),
+        }
+    }
+
+    let results = match format {
+        BenchFormat::Table => bench::to_table(&benches),
+        BenchFormat::Json => bench::to_json(&benches),
+        BenchFormat::Markdown => bench::to_markdown(&benches),
+    };
+    match output {
+        Some(output) => std::fs::write(&output, results)
+            .with_context(|| format!("Couldn't write the results to {output}"))?,
+        None => print!("{results}"),
+    }
+
+    Ok(())
+}
+
+/// Returns whether all checked answers passed.
+fn verify(day: Option<u8>) -> Result<bool> {
+    let answers = load_answers(ANSWERS_PATH)?;
+    let answers: Vec<_> = answers
+        .into_iter()
+        .filter(|expected| day.is_none_or(|day| expected.day == day))
+        .filter(|expected| expected.ignore.is_none())
+        .collect();
+
+    if answers.is_empty() {
+        bail!("There are no answers to verify in {ANSWERS_PATH}");
+    }
+
+    println!(
+        "{:>3} {:>4}  {:<30} {:>20} {:>20}  Result",
+        "Day", "Part", "Input", "Expected", "Actual"
+    );
+
+    let mut passed = 0;
+    for expected in &answers {
+        let (actual, result) = match expected.verify() {
+            Outcome::Pass => {
+                passed += 1;
+                (expected.answer.clone(), "ok".to_string())
+            }
+            Outcome::Fail(actual) => (actual.to_string(), "FAILED".to_string()),
+            Outcome::Error(err) => ("-".to_string(), format!("ERROR: {err:#}")),
+        };
+
+        println!(
+            "{:>3} {:>4}  {:<30} {:>20} {:>20}  {result}",
+            expected.day,
+            expected.part,
+            expected.input_label(),
+            expected.answer,
+            actual
+        );
+    }
+
+    println!("\n{passed} of {} answers are correct", answers.len());
+    Ok(passed == answers.len())
+}
+
+async fn load(
+    day: u8,
+    year: u16,
+    output: Option<String>,
+    force: bool,
+    session: Option<String>,
+) -> Result<()> {
+    let config = Config::load()?;
+    let puzzle = Puzzle::new(year, day);
+    let path = puzzle.input_path(&output.unwrap_or(config.input_path(year)));
+
+    let download = fetch_input(
+        &puzzle.input_url(&config::base_url(&config)),
+        &path,
+        || config::session(session, &config),
+        force,
+    )
+    .await
+    .with_context(|| format!("Error downloading input file for {year} day {day}"))?;
+    match download {
+        Download::Saved => println!("Input file saved as '{}'.", path.display()),
+        Download::Cached => println!(
+            "Input file '{}' already exists, use --force to download it again.",
+            path.display()
+        ),
+    }
+
+    Ok(())
+}
+
+async fn samples(
+    day: u8,
+    year: u16,
+    page: Option<String>,
+    pick: Vec<usize>,
+    force: bool,
+    session: Option<String>,
+) -> Result<()> {
+    let html = match page {
+        Some(page) => std::fs::read_to_string(&page)
+            .with_context(|| format!("Couldn't read the puzzle page {page}"))?,
+        None => {
+            let config = Config::load()?;
+            let cookie = config::session(session, &config).ok();
+            let url = Puzzle::new(year, day).puzzle_url(&config::base_url(&config));
+            fetch_puzzle(&url, cookie.as_deref())
+                .await
+                .with_context(|| format!("Error downloading the puzzle of {year} day {day}"))?
+        }
+    };
+
+    let samples = extract_samples(&html);
+    if samples.is_empty() {
+        bail!("The puzzle of day {day} has no samples");
+    }
+
+    if pick.is_empty() {
+        for (k, sample) in samples.iter().enumerate() {
+            println!("Sample {}:\n{sample}", k + 1);
+        }
+        println!("Save samples using --pick, e.g. --pick 1,3");
+        return Ok(());
+    }
+
+    for path in save_samples(&samples, &pick, Path::new(SAMPLES_DIR), day, force)? {
+        println!("Sample saved as '{}'.", path.display());
+    }
+
+    Ok(())
+}
+
+async fn submit(
+    puzzle: Puzzle,
+    part: u8,
+    answer: Option<String>,
+    input: Option<Input>,
+    session: Option<String>,
+) -> Result<()> {
+    let Puzzle { year, day } = puzzle;
+    let answer = match answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let input = input.unwrap_or(Input::data(&format!("{day}.input")));
+            let solution = aoc23::days::solution(day)
+                .with_context(|| format!("Day {day} is not solved yet"))?;
+            let answer = solution
+                .solve(&input, part)
+                .with_context(|| format!("Failed to solve day {day} part {part} using {input}"))?;
+            answer.to_string()
+        }
+    };
+
+    let ledger_path = Path::new(LEDGER_PATH);
+    let mut ledger = Ledger::load(ledger_path)?;
+    ledger
+        .check(year, day, part, &answer)
+        .with_context(|| format!("Refusing to submit {answer}"))?;
+
+    let config = Config::load()?;
+    let cookie = config::session(session, &config)?;
+    let verdict = submit_answer(
+        &puzzle.answer_url(&config::base_url(&config)),
+        &cookie,
+        part,
+        &answer,
+    )
+    .await
+    .with_context(|| format!("Error submitting the answer for {year} day {day} part {part}"))?;
+
+    ledger.record(Submission::new(year, day, part, &answer, verdict));
+    ledger.save(ledger_path)?;
+    println!("Day {day} Part {part}: {answer} is {verdict}");
+
     Ok(())
 }
 
 #[tokio::main]
 async fn main() {
+    dotenv::dotenv().ok();
     let args = Args::parse();
-    let cookie = dotenv!("AOC_COOKIE");
 
     match args.subcommand {
-        Subcommand::Load { day } => {
-            if (fetch_url(
-                format!("https://adventofcode.com/2023/day/{day}/input"),
-                format!("data/{day}.input"),
-                cookie.into(),
-            )
-            .await)
-                .is_ok()
-            {
-                println!("Input file saved as '{day}.input'.")
-            } else {
-                eprintln! {"Error downloading input file for day{day}."}
+        Subcommand::Load {
+            day,
+            year,
+            output,
+            force,
+        } => {
+            if let Err(err) = load(day, year, output, force, args.session).await {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Subcommand::New { day } => match aoc23::scaffold::scaffold(Path::new("."), day) {
+            Ok(files) => {
+                for file in files {
+                    println!("Wrote '{}'.", file.display());
+                }
+            }
+            Err(err) => {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        },
+        Subcommand::Samples {
+            day,
+            year,
+            page,
+            pick,
+            force,
+        } => {
+            if let Err(err) = samples(day, year, page, pick, force, args.session).await {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Subcommand::Run { day, part, input } => {
+            if let Err(err) = run(day, part, input) {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Subcommand::Submit {
+            day,
+            part,
+            answer,
+            from_run: _,
+            input,
+            year,
+        } => {
+            let puzzle = Puzzle::new(year, day);
+            if let Err(err) = submit(puzzle, part, answer, input, args.session).await {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Subcommand::Bench {
+            days,
+            iterations,
+            format,
+            output,
+        } => {
+            if let Err(err) = bench(days, iterations, format, output) {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
             }
         }
+        Subcommand::Verify { day } => match verify(day) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(err) => {
+                eprintln!("Error: {err:#}");
+                std::process::exit(1);
+            }
+        },
         Subcommand::Usage => usage(),
     }
 }
@@ -0,0 +1,60 @@
+//! Spreading independent work over all cores when the `parallel` feature is on.
+//!
+//! Results keep the order of the items either way, so answers don't depend on the feature.
+
+/// Applies `f` to every item, in parallel with the `parallel` feature.
+pub fn map<T, R, F>(items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return map_parallel(items, f);
+
+    #[cfg(not(feature = "parallel"))]
+    map_serial(items, f)
+}
+
+pub fn map_serial<T, R>(items: impl IntoIterator<Item = T>, f: impl Fn(T) -> R) -> Vec<R> {
+    items.into_iter().map(f).collect()
+}
+
+#[cfg(feature = "parallel")]
+pub fn map_parallel<T, R, F>(items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    items
+        .into_iter()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(f)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_test() {
+        let squares = map(1..=5, |n: u64| n * n);
+        assert_eq!(squares, [1, 4, 9, 16, 25]);
+        assert_eq!(squares, map_serial(1..=5, |n: u64| n * n));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn map_parallel_test() {
+        let items: Vec<u64> = (0..10_000).collect();
+        assert_eq!(
+            map_parallel(items.clone(), |n| n * 3 + 1),
+            map_serial(items, |n| n * 3 + 1)
+        );
+    }
+}
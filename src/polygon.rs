@@ -0,0 +1,206 @@
+use crate::{Direction, Vec2D};
+use num::{integer::gcd, Integer, PrimInt, Signed};
+
+/// Closed polygon with integer vertices, the last vertex connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T = i32> {
+    vertices: Vec<Vec2D<T>>,
+}
+
+impl<T: PrimInt + Signed + Integer> Polygon<T> {
+    pub fn new(vertices: Vec<Vec2D<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Follows the moves from `start`, every move ending in a new vertex.
+    ///
+    /// A last move back to `start` doesn't add it a second time.
+    pub fn from_moves(start: Vec2D<T>, moves: impl IntoIterator<Item = (Direction, T)>) -> Self {
+        let mut vertices = vec![start];
+        let mut current = start;
+        for (direction, steps) in moves {
+            current = current + Vec2D::from(direction) * steps;
+            vertices.push(current);
+        }
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vec2D<T>] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2D<T>, Vec2D<T>)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the area from the shoelace formula, so it stays an integer.
+    ///
+    /// Positive if the vertices go clockwise with `y` growing downwards.
+    pub fn signed_double_area(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |area, (a, b)| area + a.x * b.y - a.y * b.x)
+    }
+
+    /// Twice the enclosed area.
+    pub fn double_area(&self) -> T {
+        self.signed_double_area().abs()
+    }
+
+    /// Number of integer points on the edges.
+    pub fn boundary_points(&self) -> T {
+        self.edges().fold(T::zero(), |points, (a, b)| {
+            let edge = b - a;
+            points + gcd(edge.x, edge.y)
+        })
+    }
+
+    /// Number of integer points strictly inside, from Pick's theorem.
+    pub fn interior_points(&self) -> T {
+        let two = T::one() + T::one();
+        (self.double_area() - self.boundary_points() + two) / two
+    }
+
+    /// Number of integer points inside or on the edges.
+    pub fn lattice_points(&self) -> T {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, point: Vec2D<T>) -> bool {
+        self.edges().any(|(a, b)| {
+            let (edge, offset) = (b - a, point - a);
+            edge.x * offset.y == edge.y * offset.x
+                && a.x.min(b.x) <= point.x
+                && point.x <= a.x.max(b.x)
+                && a.y.min(b.y) <= point.y
+                && point.y <= a.y.max(b.y)
+        })
+    }
+
+    /// How often the polygon winds around `point`, 0 if it is outside.
+    ///
+    /// Points on the boundary are not handled, check [`Polygon::on_boundary`] first.
+    pub fn winding_number(&self, point: Vec2D<T>) -> i32 {
+        self.edges().fold(0, |winding, (a, b)| {
+            let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+            if a.y <= point.y && b.y > point.y && side > T::zero() {
+                winding + 1
+            } else if a.y > point.y && b.y <= point.y && side < T::zero() {
+                winding - 1
+            } else {
+                winding
+            }
+        })
+    }
+
+    /// Whether `point` is inside the polygon or on its boundary.
+    pub fn contains(&self, point: Vec2D<T>) -> bool {
+        self.on_boundary(point) || self.winding_number(point) != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+    use Direction::*;
+
+    fn rectangle() -> Polygon {
+        Polygon::from_moves(Vec2D::ZERO, [(Right, 6), (Down, 4), (Left, 6), (Up, 4)])
+    }
+
+    #[test]
+    fn from_moves_test() {
+        assert_eq!(
+            rectangle().vertices(),
+            [
+                Vec2D::new(0, 0),
+                Vec2D::new(6, 0),
+                Vec2D::new(6, 4),
+                Vec2D::new(0, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn area_test() {
+        let rectangle = rectangle();
+        assert_eq!(rectangle.signed_double_area(), 48);
+
+        let mut vertices = rectangle.vertices().to_vec();
+        vertices.reverse();
+        let reversed = Polygon::new(vertices);
+        assert_eq!(reversed.signed_double_area(), -48);
+        assert_eq!(reversed.double_area(), 48);
+
+        let triangle = Polygon::new(vec![Vec2D::new(0, 0), Vec2D::new(3, 0), Vec2D::new(0, 1)]);
+        assert_eq!(triangle.double_area(), 3);
+    }
+
+    #[test]
+    fn lattice_points_test() {
+        let rectangle = rectangle();
+        assert_eq!(rectangle.boundary_points(), 20);
+        assert_eq!(rectangle.interior_points(), 15);
+        assert_eq!(rectangle.lattice_points(), 7 * 5);
+
+        let diagonal = Polygon::new(vec![Vec2D::new(0, 0), Vec2D::new(4, 2), Vec2D::new(0, 2)]);
+        assert_eq!(diagonal.boundary_points(), 2 + 4 + 2);
+        assert_eq!(diagonal.interior_points(), 1);
+    }
+
+    #[test]
+    fn lattice_points_i64_test() {
+        let square = Polygon::<i64>::from_moves(
+            Vec2D::new(0, 0),
+            [(Right, 1_000_000), (Down, 1_000_000), (Left, 1_000_000)],
+        );
+        assert_eq!(square.lattice_points(), 1_000_001 * 1_000_001);
+    }
+
+    #[test_case(Vec2D::new(3, 2), true; "inside")]
+    #[test_case(Vec2D::new(6, 2), true; "on an edge")]
+    #[test_case(Vec2D::new(0, 4), true; "on a vertex")]
+    #[test_case(Vec2D::new(7, 2), false; "right of it")]
+    #[test_case(Vec2D::new(3, -1), false; "above it")]
+    fn contains_test(point: Vec2D, expected: bool) {
+        assert_eq!(rectangle().contains(point), expected);
+    }
+
+    #[test]
+    fn winding_number_test() {
+        // A U shape, the gap between its arms is outside.
+        let u = Polygon::from_moves(
+            Vec2D::ZERO,
+            [
+                (Right, 2),
+                (Down, 4),
+                (Right, 2),
+                (Up, 4),
+                (Right, 2),
+                (Down, 6),
+                (Left, 6),
+            ],
+        );
+        assert_ne!(u.winding_number(Vec2D::new(1, 2)), 0);
+        assert_eq!(u.winding_number(Vec2D::new(3, 2)), 0);
+        assert_ne!(u.winding_number(Vec2D::new(3, 5)), 0);
+
+        let clockwise = rectangle().winding_number(Vec2D::new(1, 1));
+        let mut vertices = rectangle().vertices().to_vec();
+        vertices.reverse();
+        assert_eq!(
+            Polygon::new(vertices).winding_number(Vec2D::new(1, 1)),
+            -clockwise
+        );
+
+        let twice = Polygon::new([rectangle().vertices(), rectangle().vertices()].concat());
+        assert_eq!(twice.winding_number(Vec2D::new(1, 1)), 2 * clockwise);
+    }
+}
@@ -0,0 +1,336 @@
+//! Drawing grids as coloured terminal text, SVG or PPM pictures.
+
+use crate::{input::Input, Grid, Solution, Vec2D};
+use anyhow::{Context, Result};
+use std::{fmt::Write, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(240, 200, 40);
+    pub const ORANGE: Rgb = Rgb(240, 130, 30);
+    pub const PURPLE: Rgb = Rgb(150, 90, 200);
+}
+
+/// How a single cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub glyph: char,
+    pub color: Rgb,
+    /// Set by highlights, the background of the whole picture otherwise.
+    pub background: Option<Rgb>,
+}
+
+impl Tile {
+    pub fn new(glyph: char, color: Rgb) -> Self {
+        Self {
+            glyph,
+            color,
+            background: None,
+        }
+    }
+}
+
+/// The file formats a [`Canvas`] can be written as.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Text with 24-bit colour escape codes.
+    Ansi,
+    Svg,
+    /// Binary PPM, one square of pixels per cell.
+    Ppm,
+}
+
+impl Format {
+    /// `.svg` and `.ppm` files are pictures, everything else gets the terminal text.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("svg") => Format::Svg,
+            Some("ppm") => Format::Ppm,
+            _ => Format::Ansi,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ansi => "txt",
+            Format::Svg => "svg",
+            Format::Ppm => "ppm",
+        }
+    }
+}
+
+/// Tiles for every cell of a grid, with highlights drawn on top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    tiles: Grid<Tile>,
+}
+
+impl Canvas {
+    const CELL_SIZE: usize = 12;
+    const PIXELS_PER_CELL: usize = 4;
+
+    pub fn new<T>(grid: &Grid<T>, mut tile: impl FnMut(&T) -> Tile) -> Self {
+        let tiles = grid.iter().map(|(_, cell)| tile(cell)).collect();
+
+        Self {
+            tiles: Grid::new(grid.width(), grid.height(), tiles).expect("Has the size of the grid"),
+        }
+    }
+
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+
+    /// Colours the background of `cells`, covering earlier highlights.
+    ///
+    /// Cells outside of the canvas are ignored.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Vec2D>, color: Rgb) -> Self {
+        for cell in cells {
+            if let Some(tile) = self.tiles.get_mut(cell) {
+                tile.background = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Replaces the glyph and colour of `cells`, keeping their background.
+    pub fn mark(mut self, cells: impl IntoIterator<Item = Vec2D>, glyph: char, color: Rgb) -> Self {
+        for cell in cells {
+            if let Some(tile) = self.tiles.get_mut(cell) {
+                tile.glyph = glyph;
+                tile.color = color;
+            }
+        }
+        self
+    }
+
+    /// Only the glyphs, without any colours.
+    pub fn to_text(&self) -> String {
+        self.tiles
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| tile.glyph)
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+        for row in self.tiles.rows() {
+            let mut style = None;
+            for tile in row {
+                if style != Some((tile.color, tile.background)) {
+                    let Rgb(r, g, b) = tile.color;
+                    write!(ansi, "\x1b[0;38;2;{r};{g};{b}").unwrap();
+                    if let Some(Rgb(r, g, b)) = tile.background {
+                        write!(ansi, ";48;2;{r};{g};{b}").unwrap();
+                    }
+                    ansi.push('m');
+                    style = Some((tile.color, tile.background));
+                }
+                ansi.push(tile.glyph);
+            }
+            ansi.push_str("\x1b[0m\n");
+        }
+        ansi
+    }
+
+    pub fn to_svg(&self) -> String {
+        let size = Self::CELL_SIZE;
+        let (width, height) = (self.tiles.width() * size, self.tiles.height() * size);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{size}\" \
+             text-anchor=\"middle\" dominant-baseline=\"central\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            hex(Rgb::BLACK)
+        );
+        for (position, tile) in self.tiles.iter() {
+            let (x, y) = (position.x as usize * size, position.y as usize * size);
+            if let Some(background) = tile.background {
+                writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"{}\"/>",
+                    hex(background)
+                )
+                .unwrap();
+            }
+            if !tile.glyph.is_whitespace() {
+                writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                    x + size / 2,
+                    y + size / 2,
+                    hex(tile.color),
+                    escape_xml(tile.glyph)
+                )
+                .unwrap();
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Every cell is a square in its background colour, or its glyph colour if
+    /// it has neither a background nor a blank glyph.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let scale = Self::PIXELS_PER_CELL;
+        let (width, height) = (self.tiles.width() * scale, self.tiles.height() * scale);
+
+        let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+        for row in self.tiles.rows() {
+            let pixels: Vec<u8> = row
+                .iter()
+                .flat_map(|tile| {
+                    let Rgb(r, g, b) = match tile.background {
+                        Some(background) => background,
+                        None if tile.glyph.is_whitespace() => Rgb::BLACK,
+                        None => tile.color,
+                    };
+                    [r, g, b].repeat(scale)
+                })
+                .collect();
+            for _ in 0..scale {
+                ppm.extend_from_slice(&pixels);
+            }
+        }
+        ppm
+    }
+
+    pub fn to_format(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ansi => self.to_ansi().into_bytes(),
+            Format::Svg => self.to_svg().into_bytes(),
+            Format::Ppm => self.to_ppm(),
+        }
+    }
+
+    /// Writes to `target` in the format of its extension, `-` prints to the terminal.
+    pub fn save(&self, target: &str) -> Result<()> {
+        if target == "-" {
+            print!("{}", self.to_ansi());
+            return Ok(());
+        }
+
+        let path = Path::new(target);
+        std::fs::write(path, self.to_format(Format::from_path(path)))
+            .with_context(|| format!("Couldn't write the rendering to {target}"))
+    }
+}
+
+fn hex(Rgb(r, g, b): Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_xml(glyph: char) -> String {
+    match glyph {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        glyph => glyph.to_string(),
+    }
+}
+
+/// A solution that can draw its puzzle.
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Result<Canvas>;
+
+    /// Reads and parses `input` and saves the drawing to `target`, see [`Canvas::save`].
+    fn render_to(input: impl Into<Input>, target: &str) -> Result<()> {
+        let input = Self::parse(&input.into().read()?)?;
+        Self::render(&input)?.save(target)
+    }
+}
+
+/// Command line of the day binaries that can draw their puzzle.
+#[derive(Debug, clap::Parser)]
+pub struct RenderArgs {
+    /// Draw the puzzle instead of solving it. `.svg` and `.ppm` files get a picture,
+    /// other files the coloured text and `-` prints it
+    #[arg(long)]
+    pub render: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::parse_with("#.\n.#\n", |c| Ok(c == '#')).unwrap();
+        Canvas::new(&grid, |&wall| match wall {
+            true => Tile::new('#', Rgb::WHITE),
+            false => Tile::new(' ', Rgb::GRAY),
+        })
+    }
+
+    #[test]
+    fn highlight_test() {
+        let canvas = canvas()
+            .highlight([Vec2D::new(0, 0), Vec2D::new(1, 0)], Rgb::RED)
+            .highlight([Vec2D::new(1, 0), Vec2D::new(5, 5)], Rgb::BLUE)
+            .mark([Vec2D::new(0, 1)], 'S', Rgb::GREEN);
+
+        assert_eq!(canvas.to_text(), "# \nS#\n");
+        assert_eq!(canvas.tiles()[Vec2D::new(0, 0)].background, Some(Rgb::RED));
+        assert_eq!(canvas.tiles()[Vec2D::new(1, 0)].background, Some(Rgb::BLUE));
+        assert_eq!(canvas.tiles()[Vec2D::new(1, 1)].background, None);
+    }
+
+    #[test]
+    fn ansi_test() {
+        let ansi = canvas().highlight([Vec2D::new(1, 1)], Rgb::RED).to_ansi();
+
+        assert_eq!(
+            ansi,
+            "\x1b[0;38;2;255;255;255m#\x1b[0;38;2;110;110;110m \x1b[0m\n\
+             \x1b[0;38;2;110;110;110m \x1b[0;38;2;255;255;255;48;2;220;50;47m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn svg_test() {
+        let svg = canvas().highlight([Vec2D::new(1, 0)], Rgb::RED).to_svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\""));
+        assert!(
+            svg.contains("<rect x=\"12\" y=\"0\" width=\"12\" height=\"12\" fill=\"#dc322f\"/>")
+        );
+        assert_eq!(svg.matches("<text").count(), 2);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn ppm_test() {
+        let ppm = canvas().highlight([Vec2D::new(1, 0)], Rgb::RED).to_ppm();
+
+        let header = b"P6\n8 8\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 8 * 8 * 3);
+        assert_eq!(&pixels[..3], &[255, 255, 255]);
+        assert_eq!(&pixels[4 * 3..4 * 3 + 3], &[220, 50, 47]);
+        // Blank cells without a highlight are black.
+        assert_eq!(&pixels[8 * 4 * 3..8 * 4 * 3 + 3], &[0, 0, 0]);
+    }
+
+    #[test_case("out.svg", Format::Svg)]
+    #[test_case("out.ppm", Format::Ppm)]
+    #[test_case("out.txt", Format::Ansi)]
+    #[test_case("out", Format::Ansi)]
+    fn format_test(path: &str, expected: Format) {
+        assert_eq!(Format::from_path(Path::new(path)), expected);
+    }
+}
@@ -0,0 +1,182 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+pub const SAMPLES_DIR: &str = "data";
+
+/// Extracts the content of every `<pre><code>` block of a puzzle page.
+///
+/// Inline markup like `<em>` is dropped and HTML entities are decoded, so the
+/// blocks are exactly the example inputs as shown on the page.
+pub fn extract_samples(html: &str) -> Vec<String> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let mut samples = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(START) {
+        rest = &rest[start + START.len()..];
+        let end = rest.find(END).unwrap_or(rest.len());
+        samples.push(decode_entities(&strip_tags(&rest[..end])));
+        rest = &rest[end..];
+    }
+
+    samples
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Paths for the picked samples of a day, `N_k.sample` for sample `k`.
+pub fn sample_paths(dir: &Path, day: u8, picks: &[usize]) -> Vec<PathBuf> {
+    picks
+        .iter()
+        .map(|k| dir.join(format!("{day}_{k}.sample")))
+        .collect()
+}
+
+/// Saves the picked samples (numbered from 1) into `dir`.
+///
+/// Nothing is written if any of the files already exists, unless `force` is set.
+pub fn save_samples(
+    samples: &[String],
+    picks: &[usize],
+    dir: &Path,
+    day: u8,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    if let Some(pick) = picks
+        .iter()
+        .find(|&&pick| pick == 0 || pick > samples.len())
+    {
+        bail!(
+            "There is no sample {pick}, the puzzle has {} samples",
+            samples.len()
+        );
+    }
+
+    let paths = sample_paths(dir, day, picks);
+    if let Some(path) = paths.iter().find(|path| path.exists() && !force) {
+        bail!(
+            "{} already exists, use --force to overwrite it",
+            path.display()
+        );
+    }
+
+    for (pick, path) in picks.iter().zip(&paths) {
+        std::fs::write(path, &samples[pick - 1])
+            .with_context(|| format!("Couldn't write {}", path.display()))?;
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    const PUZZLE: &str = r#"<article class="day-desc"><h2>--- Day 0: Test ---</h2>
+<p>For example, with <code>1abc2</code> as an input:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Here is a map with <em>highlights</em>:</p>
+<pre><code>.|...\....
+<em>&gt;</em>-&lt;.&amp;.
+</code></pre>
+</article>"#;
+
+    #[test]
+    fn extract_samples_test() {
+        assert_eq!(
+            extract_samples(PUZZLE),
+            vec!["1abc2\npqr3stu8vwx\n", ".|...\\....\n>-<.&.\n"]
+        );
+    }
+
+    /// A puzzle page as saved by a browser after solving both parts.
+    const SAVED_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head>
+<meta charset="utf-8"/>
+<title>Day 1 - Advent of Code 2023</title>
+<link rel="stylesheet" type="text/css" href="Day%201%20-%20Advent%20of%20Code%202023_files/style.css"/>
+</head><!--
+
+
+
+
+Oh, hello!  Funny seeing you here.
+--><body>
+<header><div><h1 class="title-global"><a href="https://adventofcode.com/">Advent of Code</a></h1><nav><ul><li><a href="https://adventofcode.com/2023/about">[About]</a></li></ul></nav></div></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55108</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>56324</code>.</p><p class="day-success">Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main>
+</body></html>"#;
+
+    #[test]
+    fn extract_saved_page_test() {
+        assert_eq!(
+            extract_samples(SAVED_PAGE),
+            vec![
+                "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+                "two1nine\neightwothree\nabcone2threexyz\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn save_samples_test() {
+        let dir = temp_dir("save_samples");
+        let samples = extract_samples(PUZZLE);
+
+        let paths = save_samples(&samples, &[2], &dir, 7, false).unwrap();
+        assert_eq!(paths, vec![dir.join("7_2.sample")]);
+        assert_eq!(std::fs::read_to_string(&paths[0]).unwrap(), samples[1]);
+
+        assert!(save_samples(&samples, &[1, 2], &dir, 7, false).is_err());
+        assert!(!dir.join("7_1.sample").exists());
+
+        let paths = save_samples(&samples, &[1, 2], &dir, 7, true).unwrap();
+        assert_eq!(paths, vec![dir.join("7_1.sample"), dir.join("7_2.sample")]);
+        assert_eq!(std::fs::read_to_string(&paths[0]).unwrap(), samples[0]);
+        assert_eq!(std::fs::read_to_string(&paths[1]).unwrap(), samples[1]);
+
+        assert!(save_samples(&samples, &[3], &dir, 8, false).is_err());
+        assert!(save_samples(&samples, &[0], &dir, 8, false).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+
+fn day_template(day: u8) -> String {
+    format!(
+        r#"use crate::{{Answer, Solution}};
+use anyhow::{{bail, Result}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.to_string())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {{
+        bail!("Part 1 is not solved yet")
+    }}
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {{
+        bail!("Part 2 is not solved yet")
+    }}
+}}
+"#
+    )
+}
+
+fn bin_template(day: u8) -> String {
+    format!(
+        r#"use aoc23::{{days::day{day}::Day{day}, Solution}};
+
+fn main() {{
+    println!("Part 1: {{}}", Day{day}::solve(aoc23::puzzle_input!({day}), 1).unwrap());
+    println!("Part 2: {{}}", Day{day}::solve(aoc23::puzzle_input!({day}), 2).unwrap());
+}}
+"#
+    )
+}
+
+/// Commented out, so `aoc23 verify` and the sample tests keep passing until the
+/// answers are known.
+fn answers_template(day: u8) -> String {
+    let mut answers = format!("\n# Day {day}\n");
+    for part in 1..=2 {
+        // The sample entries become ignored tests right away, see build.rs.
+        answers += &format!(
+            "\n[[answer]]\nday = {day}\npart = {part}\ninput = \"data/{day}.sample\"\nanswer = \"\"\nignore = \"not solved yet\"\n"
+        );
+        answers += &format!(
+            "\n# [[answer]]\n# day = {day}\n# part = {part}\n# input = \"data/{day}.input\"\n# answer = \"\"\n"
+        );
+    }
+    answers
+}
+
+/// Adds the module and its entry in `SOLUTIONS` to `src/days/mod.rs`.
+fn register_day(days_mod: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{day};");
+    if days_mod.lines().any(|line| line == module) {
+        bail!("Day {day} is already registered");
+    }
+
+    let mut lines: Vec<String> = days_mod.lines().map(str::to_string).collect();
+
+    // Modules are kept in the order rustfmt sorts them.
+    let modules = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day"))
+        .context("Couldn't find the day modules")?;
+    let name = format!("day{day}");
+    let position = lines[modules..]
+        .iter()
+        .position(|line| match line.strip_prefix("pub mod ") {
+            Some(other) => other.trim_end_matches(';') > name.as_str(),
+            None => true,
+        })
+        .map_or(lines.len(), |offset| modules + offset);
+    lines.insert(position, module);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static SOLUTIONS"))
+        .context("Couldn't find SOLUTIONS")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .context("Couldn't find the end of SOLUTIONS")?;
+    let position = lines[start + 1..end]
+        .iter()
+        .position(|line| {
+            line.trim()
+                .strip_prefix("&day")
+                .and_then(|line| line.split_once(':'))
+                .and_then(|(other, _)| other.parse::<u8>().ok())
+                .is_some_and(|other| other > day)
+        })
+        .map_or(end, |offset| start + 1 + offset);
+    lines.insert(position, format!("    &day{day}::Day{day},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_bin(manifest: &str, day: u8) -> Result<String> {
+    let path = format!("path = \"src/bin/day{day}.rs\"");
+    if manifest.lines().any(|line| line == path) {
+        bail!("The binary of day {day} is already registered");
+    }
+
+    Ok(format!(
+        "{}\n\n[[bin]]\nname = \"day{day}\"\n{path}\n",
+        manifest.trim_end()
+    ))
+}
+
+/// Creates a new day from a template and registers it, relative to the project `root`.
+///
+/// Nothing is changed if any part of the day already exists. Returns every file
+/// that was created or changed.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {day}");
+    }
+
+    let solution = root.join(format!("src/days/day{day}.rs"));
+    let bin = root.join(format!("src/bin/day{day}.rs"));
+    let sample = root.join(format!("data/{day}.sample"));
+    for path in [&solution, &bin, &sample] {
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+
+    let days_mod_path = root.join("src/days/mod.rs");
+    let manifest_path = root.join("Cargo.toml");
+    let answers_path = root.join(crate::answers::ANSWERS_PATH);
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).with_context(|| format!("Couldn't read {}", path.display()))
+    };
+    let days_mod = register_day(&read(&days_mod_path)?, day)?;
+    let manifest = register_bin(&read(&manifest_path)?, day)?;
+    let answers = read(&answers_path)? + &answers_template(day);
+
+    let files = [
+        (solution, day_template(day)),
+        (bin, bin_template(day)),
+        (sample, String::new()),
+        (days_mod_path, days_mod),
+        (manifest_path, manifest),
+        (answers_path, answers),
+    ];
+    for (path, content) in &files {
+        std::fs::write(path, content)
+            .with_context(|| format!("Couldn't write {}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    const DAYS_MOD: &str = "pub mod day1;
+pub mod day10;
+pub mod day3;
+
+use crate::DynSolution;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day3::Day3,
+    &day10::Day10,
+];
+";
+
+    #[test]
+    fn register_day_test() {
+        let days_mod = register_day(DAYS_MOD, 2).unwrap();
+        assert_eq!(
+            days_mod,
+            "pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+
+use crate::DynSolution;
+
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day10::Day10,
+];
+"
+        );
+
+        let days_mod = register_day(DAYS_MOD, 11).unwrap();
+        assert!(days_mod.contains("pub mod day10;\npub mod day11;\npub mod day3;"));
+        assert!(days_mod.contains("    &day10::Day10,\n    &day11::Day11,\n];"));
+
+        assert!(register_day(DAYS_MOD, 3).is_err());
+    }
+
+    #[test]
+    fn register_bin_test() {
+        let manifest =
+            "[package]\nname = \"aoc23\"\n\n[[bin]]\nname = \"day1\"\npath = \"src/bin/day1.rs\"\n";
+
+        assert_eq!(
+            register_bin(manifest, 2).unwrap(),
+            format!("{manifest}\n[[bin]]\nname = \"day2\"\npath = \"src/bin/day2.rs\"\n")
+        );
+        assert!(register_bin(manifest, 1).is_err());
+    }
+
+    #[test]
+    fn answers_template_test() {
+        let answers = crate::answers::parse_answers(&answers_template(4)).unwrap();
+        assert_eq!(answers.len(), 2);
+        for (part, answer) in (1..=2).zip(&answers) {
+            assert_eq!((answer.day, answer.part), (4, part));
+            assert_eq!(answer.input, "data/4.sample");
+            assert_eq!(answer.ignore.as_deref(), Some("not solved yet"));
+        }
+    }
+
+    fn project(name: &str) -> PathBuf {
+        let root = temp_dir(name);
+        for dir in ["src/days", "src/bin", "data"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        std::fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[package]\nname = \"aoc23\"\n").unwrap();
+        std::fs::write(root.join("data/answers.toml"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn scaffold_test() {
+        let root = project("scaffold");
+
+        let files = scaffold(&root, 2).unwrap();
+
+        assert_eq!(files.len(), 6);
+        let solution = std::fs::read_to_string(root.join("src/days/day2.rs")).unwrap();
+        assert!(solution.contains("impl Solution for Day2"));
+        assert!(solution.contains("const DAY: u8 = 2;"));
+        let bin = std::fs::read_to_string(root.join("src/bin/day2.rs")).unwrap();
+        assert!(bin.contains("Day2::solve(aoc23::puzzle_input!(2), 1)"));
+        assert!(root.join("data/2.sample").exists());
+        let answers = std::fs::read_to_string(root.join("data/answers.toml")).unwrap();
+        assert!(answers.contains("\nday = 2\n"));
+    }
+
+    #[test]
+    fn scaffold_existing_day_test() {
+        let root = project("scaffold_existing_day");
+        std::fs::write(root.join("src/days/day2.rs"), "// solved").unwrap();
+
+        assert!(scaffold(&root, 2).is_err());
+        assert!(scaffold(&root, 3).is_err());
+        assert!(scaffold(&root, 26).is_err());
+
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/days/day2.rs")).unwrap(),
+            "// solved"
+        );
+        assert!(!root.join("src/bin/day3.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+            DAYS_MOD
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! States are anything hashable, the graph is given by a function returning the
+//! neighbours of a state. Every search stops at the first state accepted by
+//! `is_goal`, so several goals are just a predicate matching all of them.
+
+use num::Zero;
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest way to a goal, `states` goes from the start to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("A path contains at least the start")
+    }
+}
+
+/// Every state reached so far, with the cost and the state it was reached from.
+struct Visited<S, C> {
+    states: Vec<(S, Option<usize>, C)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            states: vec![(start.clone(), None, cost)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Path<S, C> {
+        let cost = self.states[i].2;
+        let mut states = vec![self.states[i].0.clone()];
+        while let Some(parent) = self.states[i].1 {
+            states.push(self.states[parent].0.clone());
+            i = parent;
+        }
+        states.reverse();
+
+        Path { cost, states }
+    }
+}
+
+/// Breadth first search, the cost is the number of steps.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        let (state, _, steps) = visited.states[i].clone();
+        if is_goal(&state) {
+            return Some(visited.path(i));
+        }
+
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = visited.index.entry(next.clone()) {
+                entry.insert(visited.states.len());
+                queue.push_back(visited.states.len());
+                visited.states.push((next, Some(i), steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, `neighbors` returns every next state with the cost to get there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::zero(), is_goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut visited = Visited::new(start, C::zero());
+
+    while let Some(Reverse((estimate, i))) = heap.pop() {
+        let (state, _, cost) = visited.states[i].clone();
+        if estimate > cost + heuristic(&state) {
+            // Reached again more cheaply after this entry was pushed.
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(visited.path(i));
+        }
+
+        for (next, step_cost) in neighbors(&state) {
+            let next_cost = cost + step_cost;
+            let next_index = match visited.index.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(visited.states.len());
+                    visited.states.push((next.clone(), Some(i), next_cost));
+                    visited.states.len() - 1
+                }
+                Entry::Occupied(entry) => {
+                    let reached = &mut visited.states[*entry.get()];
+                    if next_cost >= reached.2 {
+                        continue;
+                    }
+                    reached.1 = Some(i);
+                    reached.2 = next_cost;
+                    *entry.get()
+                }
+            };
+            heap.push(Reverse((next_cost + heuristic(&next), next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Grid, Vec2D};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+...#...
+";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse_with(MAZE, |c| Ok(c == '#')).unwrap()
+    }
+
+    fn open_neighbors(maze: &Grid<bool>, position: Vec2D) -> Vec<Vec2D> {
+        maze.neighbors4(position)
+            .filter(|&neighbor| !maze[neighbor])
+            .collect()
+    }
+
+    fn is_step(path: &[Vec2D]) -> bool {
+        path.windows(2).all(|pair| pair[0].manhattan(pair[1]) == 1)
+    }
+
+    #[test]
+    fn bfs_test() {
+        let maze = maze();
+        let goal = Vec2D::new(6, 0);
+
+        let path = bfs(
+            Vec2D::ZERO,
+            |&position| open_neighbors(&maze, position),
+            |&position| position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states[0], Vec2D::ZERO);
+        assert_eq!(*path.goal(), goal);
+        assert!(is_step(&path.states));
+    }
+
+    #[test]
+    fn bfs_unreachable_test() {
+        let maze = maze();
+
+        let path = bfs(
+            Vec2D::ZERO,
+            |&position| open_neighbors(&maze, position),
+            |&position| position == Vec2D::new(2, 0),
+        );
+
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn bfs_multiple_goals_test() {
+        let maze = maze();
+
+        let path = bfs(
+            Vec2D::ZERO,
+            |&position| open_neighbors(&maze, position),
+            |&position| position == Vec2D::new(6, 4) || position == Vec2D::new(2, 4),
+        )
+        .unwrap();
+
+        assert_eq!(*path.goal(), Vec2D::new(2, 4));
+        assert_eq!(path.cost, 6);
+    }
+
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let path = dijkstra('a', graph, |&node| node == 'e').unwrap();
+
+        assert_eq!(
+            path,
+            Path {
+                cost: 20,
+                states: vec!['a', 'c', 'f', 'e'],
+            }
+        );
+        assert_eq!(dijkstra('a', graph, |&node| node == 'z'), None);
+    }
+
+    #[test]
+    fn dijkstra_multiple_goals_test() {
+        let path = dijkstra('a', graph, |node| "de".contains(*node)).unwrap();
+        assert_eq!(path.cost, 20);
+
+        let path = dijkstra('a', graph, |node| "bd".contains(*node)).unwrap();
+        assert_eq!(path.states, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn astar_test() {
+        let maze = maze();
+        let goal = Vec2D::new(6, 4);
+        let neighbors = |&position: &Vec2D| {
+            open_neighbors(&maze, position)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+        };
+
+        let path = astar(
+            Vec2D::ZERO,
+            neighbors,
+            |&position| position.manhattan(goal),
+            |&position| position == goal,
+        )
+        .unwrap();
+        let expected = dijkstra(Vec2D::ZERO, neighbors, |&position| position == goal).unwrap();
+
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.states.len(), path.cost as usize + 1);
+        assert_eq!(*path.goal(), goal);
+        assert!(is_step(&path.states));
+    }
+}
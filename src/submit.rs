@@ -0,0 +1,329 @@
+use crate::download::DownloadError;
+use anyhow::{bail, Context, Result};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub const LEDGER_PATH: &str = "data/submissions.toml";
+
+/// How adventofcode.com judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Answered too recently, the number of seconds left to wait.
+    Wait(u64),
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(seconds) => write!(f, "too soon, wait {seconds}s"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Reads the verdict from the page returned after submitting an answer.
+pub fn parse_verdict(page: &str) -> Option<Verdict> {
+    if page.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else if page.contains("You gave an answer too recently") {
+        Some(Verdict::Wait(parse_wait(page).unwrap_or(60)))
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else {
+            Some(Verdict::Wrong)
+        }
+    } else {
+        None
+    }
+}
+
+/// Parses durations like `You have 1m 5s left to wait` into seconds.
+fn parse_wait(page: &str) -> Option<u64> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (amount, unit) = part.split_at(part.len().checked_sub(1)?);
+            let amount: u64 = amount.parse().ok()?;
+            match unit {
+                "h" => Some(amount * 60 * 60),
+                "m" => Some(amount * 60),
+                "s" => Some(amount),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// Posts `answer` for the given part to the answer endpoint at `url`.
+pub async fn submit_answer(url: &str, cookie: &str, part: u8, answer: &str) -> Result<Verdict> {
+    let res = reqwest::Client::new()
+        .post(url)
+        .header(header::COOKIE, format!("session={cookie}"))
+        .form(&[("level", part.to_string().as_str()), ("answer", answer)])
+        .send()
+        .await
+        .map_err(DownloadError::Network)?;
+
+    if !res.status().is_success() {
+        return Err(DownloadError::from(res.status()).into());
+    }
+    let page = res.text().await.map_err(DownloadError::Network)?;
+
+    parse_verdict(&page).context("Couldn't understand the response to the answer")
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+impl Submission {
+    pub fn new(year: u16, day: u8, part: u8, answer: &str, verdict: Verdict) -> Self {
+        Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: now(),
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0)
+}
+
+/// Every answer submitted so far, used to avoid sending answers that can't be right.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Loads the ledger, a missing file is the same as an empty ledger.
+    pub fn load(path: &Path) -> Result<Ledger> {
+        if !path.exists() {
+            return Ok(Ledger::default());
+        }
+
+        let ledger = std::fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read the submissions from {}", path.display()))?;
+        toml::from_str(&ledger)
+            .with_context(|| format!("Couldn't parse the submissions in {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Couldn't write the submissions to {}", path.display()))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Fails if submitting `answer` can't be right or has to wait, based on earlier submissions.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<()> {
+        let earlier = self
+            .submissions
+            .iter()
+            .filter(|submission| {
+                submission.year == year && submission.day == day && submission.part == part
+            })
+            .collect::<Vec<_>>();
+        let number = answer.parse::<i128>().ok();
+
+        for submission in &earlier {
+            let ordering = number
+                .zip(submission.answer.parse::<i128>().ok())
+                .map(|(number, earlier)| number.cmp(&earlier));
+            match submission.verdict {
+                Verdict::Correct => bail!(
+                    "Day {day} part {part} is already solved with {}",
+                    submission.answer
+                ),
+                Verdict::AlreadySolved => bail!("Day {day} part {part} is already solved"),
+                Verdict::Wait(_) => {}
+                verdict if submission.answer == answer => {
+                    bail!("{answer} was already submitted and is {verdict}")
+                }
+                Verdict::TooHigh if ordering.is_some_and(Ordering::is_ge) => {
+                    bail!("{answer} is too high, {} already was", submission.answer)
+                }
+                Verdict::TooLow if ordering.is_some_and(Ordering::is_le) => {
+                    bail!("{answer} is too low, {} already was", submission.answer)
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(Submission {
+            verdict: Verdict::Wait(seconds),
+            submitted_at,
+            ..
+        }) = earlier.last()
+        {
+            let wait_until = submitted_at + seconds;
+            if now() < wait_until {
+                bail!(
+                    "Answered too recently, wait another {}s",
+                    wait_until - now()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{temp_dir, TestServer};
+    use test_case::test_case;
+
+    #[test_case(
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+        Some(Verdict::Correct)
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        Some(Verdict::TooHigh)
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        Some(Verdict::TooLow)
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer.  If you're stuck, ...</p></article>",
+        Some(Verdict::Wrong)
+    )]
+    #[test_case("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>", Some(Verdict::Wait(65)))]
+    #[test_case("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>", Some(Verdict::AlreadySolved))]
+    #[test_case("<html>Something else</html>", None)]
+    fn parse_verdict_test(page: &str, expected: Option<Verdict>) {
+        assert_eq!(parse_verdict(page), expected);
+    }
+
+    fn ledger(submissions: &[(&str, Verdict)]) -> Ledger {
+        Ledger {
+            submissions: submissions
+                .iter()
+                .map(|&(answer, verdict)| Submission {
+                    submitted_at: 0,
+                    ..Submission::new(2023, 1, 1, answer, verdict)
+                })
+                .collect(),
+        }
+    }
+
+    #[test_case(&[], "50", true)]
+    #[test_case(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)], "50", true)]
+    #[test_case(&[("100", Verdict::TooHigh)], "100", false)]
+    #[test_case(&[("100", Verdict::TooHigh)], "150", false)]
+    #[test_case(&[("10", Verdict::TooLow)], "5", false)]
+    #[test_case(&[("abc", Verdict::Wrong)], "abc", false)]
+    #[test_case(&[("abc", Verdict::Wrong)], "abd", true)]
+    #[test_case(&[("42", Verdict::Correct)], "43", false)]
+    #[test_case(&[("42", Verdict::AlreadySolved)], "43", false)]
+    #[test_case(&[("42", Verdict::Wait(60))], "42", true)]
+    fn check_test(submissions: &[(&str, Verdict)], answer: &str, allowed: bool) {
+        assert_eq!(
+            ledger(submissions).check(2023, 1, 1, answer).is_ok(),
+            allowed
+        );
+    }
+
+    #[test]
+    fn check_other_parts_test() {
+        let ledger = ledger(&[("42", Verdict::Correct)]);
+        assert!(ledger.check(2023, 1, 2, "42").is_ok());
+        assert!(ledger.check(2023, 2, 1, "42").is_ok());
+        assert!(ledger.check(2022, 1, 1, "42").is_ok());
+    }
+
+    #[test]
+    fn check_wait_test() {
+        let mut ledger = ledger(&[]);
+        ledger.record(Submission::new(2023, 1, 1, "42", Verdict::Wait(300)));
+        assert!(ledger.check(2023, 1, 1, "43").is_err());
+    }
+
+    #[test]
+    fn ledger_round_trip_test() {
+        let path = temp_dir("ledger_round_trip").join("submissions.toml");
+        assert_eq!(Ledger::load(&path).unwrap(), Ledger::default());
+
+        let ledger = ledger(&[("100", Verdict::TooHigh), ("42", Verdict::Wait(30))]);
+        ledger.save(&path).unwrap();
+        assert_eq!(Ledger::load(&path).unwrap(), ledger);
+    }
+
+    #[tokio::test]
+    async fn submit_answer_test() {
+        let server = TestServer::start(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )
+        .await;
+
+        let url = format!("{}/2023/day/1/answer", server.base_url);
+        let verdict = submit_answer(&url, "secret", 2, "42").await.unwrap();
+
+        assert_eq!(verdict, Verdict::TooLow);
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2023/day/1/answer "));
+        assert!(request.contains("cookie: session=secret"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[tokio::test]
+    async fn submit_expired_session_test() {
+        let server = TestServer::start(400, "").await;
+
+        let err = submit_answer(&server.base_url, "secret", 1, "42")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<DownloadError>(),
+            Some(DownloadError::ExpiredSession)
+        ));
+    }
+}